@@ -0,0 +1,25 @@
+use std::collections::hash_map::RandomState;
+use std::env;
+use std::fs;
+use std::hash::{BuildHasher, Hasher};
+use std::path::Path;
+use std::time::SystemTime;
+
+fn main() {
+    // the obfuscate feature needs a key that differs between builds
+    if env::var_os("CARGO_FEATURE_OBFUSCATE").is_none() {
+        return;
+    }
+
+    let mut hasher = RandomState::new().build_hasher();
+    if let Ok(elapsed) = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+        hasher.write_u128(elapsed.as_nanos());
+    }
+
+    let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(
+        Path::new(&out_dir).join("obfuscation_key.rs"),
+        format!("const KEY: u64 = {:#x};\n", hasher.finish()),
+    )
+    .expect("failed to write the obfuscation key");
+}
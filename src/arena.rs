@@ -0,0 +1,349 @@
+#[cfg(not(all(feature = "mlock", any(unix, windows))))]
+use std::boxed::Box;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ptr::{self, NonNull};
+use std::slice;
+use std::str;
+use std::sync::atomic::{compiler_fence, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::vec::Vec;
+
+#[cfg(all(feature = "mlock", any(unix, windows)))]
+use super::locked::Pages;
+use super::{audit, Secret};
+
+/// Size of the chunks allocated by `SecretArena::new`, in bytes
+const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Overwrites `len` bytes at `ptr` with zeros, in a way the compiler cannot optimize away
+unsafe fn wipe(ptr: *mut u8, len: usize) {
+    for i in 0..len {
+        ptr::write_volatile(ptr.add(i), 0);
+    }
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Memory of a chunk, wiped when dropped
+#[cfg(all(feature = "mlock", any(unix, windows)))]
+struct Region(Pages);
+
+#[cfg(all(feature = "mlock", any(unix, windows)))]
+impl Region {
+    fn new(size: usize) -> Region {
+        // pages are wiped when dropped
+        Region(Pages::new(size, 1).0)
+    }
+
+    #[inline]
+    fn ptr(&self) -> NonNull<u8> {
+        self.0.ptr
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.0.size()
+    }
+
+    #[inline]
+    fn is_locked(&self) -> bool {
+        self.0.protections.locked()
+    }
+}
+
+/// Memory of a chunk, wiped when dropped
+#[cfg(not(all(feature = "mlock", any(unix, windows))))]
+struct Region {
+    ptr: NonNull<u8>,
+    size: usize,
+}
+
+#[cfg(not(all(feature = "mlock", any(unix, windows))))]
+impl Region {
+    fn new(size: usize) -> Region {
+        let buf = vec![0u8; size].into_boxed_slice();
+        let ptr = NonNull::new(Box::into_raw(buf) as *mut u8).expect("box is non-null");
+        Region { ptr, size }
+    }
+
+    #[inline]
+    fn ptr(&self) -> NonNull<u8> {
+        self.ptr
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.size
+    }
+}
+
+#[cfg(not(all(feature = "mlock", any(unix, windows))))]
+impl Drop for Region {
+    fn drop(&mut self) {
+        unsafe {
+            wipe(self.ptr.as_ptr(), self.size);
+            drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+                self.ptr.as_ptr(),
+                self.size,
+            )));
+        }
+    }
+}
+
+struct Chunk {
+    region: Region,
+    used: usize,
+    /// Number of `ArenaSecret`s stored in the chunk
+    live: usize,
+}
+
+/// Pooled storage for many small secrets
+///
+/// Services holding thousands of secrets, such as per-tenant API keys, pay for one allocation per
+/// `Secret<String>`. A `SecretArena` copies secrets into large chunks instead, handing out
+/// `ArenaSecret`s that borrow from it:
+///
+/// ```rust
+/// use sec::{Secret, SecretArena};
+///
+/// let arena = SecretArena::new();
+/// let key = arena.alloc_str(&Secret::new("tenant-key".to_owned()));
+/// assert_eq!(key.reveal_str(), "tenant-key");
+/// ```
+///
+/// With the `mlock` feature, each chunk is a `SecretBox`-like allocation locked against swapping,
+/// so only one system call is needed per chunk. The `windows-protect` feature does not apply to
+/// arenas.
+///
+/// An `ArenaSecret` wipes its slot when dropped. Slots are not reused one by one, but once all
+/// secrets of a chunk have been dropped, the whole chunk is reused. Chunks are never freed before
+/// the arena is dropped, so its `capacity` grows to the peak amount of secrets held at once, plus
+/// the space of dropped secrets in chunks that also hold live ones; arenas suit many secrets
+/// with similar lifetimes best. All chunks are wiped and freed when the arena is dropped.
+pub struct SecretArena {
+    chunk_size: usize,
+    chunks: Mutex<Vec<Chunk>>,
+}
+
+// chunks are only written to while `chunks` is locked, and to the disjoint slots of `ArenaSecret`s
+unsafe impl Send for SecretArena {}
+unsafe impl Sync for SecretArena {}
+
+impl SecretArena {
+    /// Creates an empty arena, allocating chunks of 64 KiB
+    #[inline]
+    pub fn new() -> SecretArena {
+        SecretArena::with_chunk_size(DEFAULT_CHUNK_SIZE)
+    }
+
+    /// Creates an empty arena, allocating chunks of at least `chunk_size` bytes
+    ///
+    /// Secrets larger than `chunk_size` get a chunk of their own.
+    #[inline]
+    pub fn with_chunk_size(chunk_size: usize) -> SecretArena {
+        SecretArena {
+            chunk_size: chunk_size.max(1),
+            chunks: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Copies a secret string into the arena
+    #[inline]
+    pub fn alloc_str<T: AsRef<str> + ?Sized, P>(
+        &self,
+        secret: &Secret<T, P>,
+    ) -> ArenaSecret<'_, str> {
+        self.alloc(secret.1.as_ref().as_bytes())
+    }
+
+    /// Copies secret bytes into the arena
+    #[inline]
+    pub fn alloc_bytes<T: AsRef<[u8]> + ?Sized, P>(
+        &self,
+        secret: &Secret<T, P>,
+    ) -> ArenaSecret<'_, [u8]> {
+        self.alloc(secret.1.as_ref())
+    }
+
+    /// Returns the total size of all chunks, in bytes
+    pub fn capacity(&self) -> usize {
+        self.chunks().iter().map(|chunk| chunk.region.size()).sum()
+    }
+
+    /// Returns whether all chunks are locked against swapping
+    #[cfg(all(feature = "mlock", any(unix, windows)))]
+    pub fn is_locked(&self) -> bool {
+        self.chunks().iter().all(|chunk| chunk.region.is_locked())
+    }
+
+    fn chunks(&self) -> MutexGuard<'_, Vec<Chunk>> {
+        self.chunks.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Copies `data` into the first chunk it fits into, or a new one
+    fn alloc<T: ?Sized>(&self, data: &[u8]) -> ArenaSecret<'_, T> {
+        if data.is_empty() {
+            return ArenaSecret {
+                arena: self,
+                chunk: None,
+                ptr: NonNull::dangling(),
+                len: 0,
+                _marker: PhantomData,
+            };
+        }
+        let mut chunks = self.chunks();
+
+        let index = match chunks
+            .iter()
+            .position(|chunk| chunk.region.size() - chunk.used >= data.len())
+        {
+            Some(index) => index,
+            None => {
+                chunks.push(Chunk {
+                    region: Region::new(self.chunk_size.max(data.len())),
+                    used: 0,
+                    live: 0,
+                });
+                chunks.len() - 1
+            }
+        };
+
+        let chunk = &mut chunks[index];
+        let ptr = unsafe { chunk.region.ptr().as_ptr().add(chunk.used) };
+        unsafe { ptr::copy_nonoverlapping(data.as_ptr(), ptr, data.len()) };
+        chunk.used += data.len();
+        chunk.live += 1;
+
+        ArenaSecret {
+            arena: self,
+            chunk: Some(index),
+            ptr: NonNull::new(ptr).expect("chunk pointer is non-null"),
+            len: data.len(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Releases a slot of the chunk at `index`, whose contents have been wiped already
+    fn release(&self, index: usize) {
+        let mut chunks = self.chunks();
+        let chunk = &mut chunks[index];
+        chunk.live -= 1;
+        // all slots are wiped, so the chunk can be reused from its start
+        if chunk.live == 0 {
+            chunk.used = 0;
+        }
+    }
+}
+
+impl Default for SecretArena {
+    #[inline]
+    fn default() -> SecretArena {
+        SecretArena::new()
+    }
+}
+
+impl fmt::Debug for SecretArena {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SecretArena")
+            .field("chunks", &self.chunks().len())
+            .finish()
+    }
+}
+
+/// A secret stored in a `SecretArena`, either a `str` or a `[u8]`
+///
+/// The slot is wiped when the `ArenaSecret` is dropped.
+pub struct ArenaSecret<'a, T: ?Sized> {
+    arena: &'a SecretArena,
+    /// Index of the chunk holding the slot, `None` for empty secrets
+    chunk: Option<usize>,
+    ptr: NonNull<u8>,
+    len: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+// the slot is owned exclusively and only read while shared
+unsafe impl<T: ?Sized> Send for ArenaSecret<'_, T> {}
+unsafe impl<T: ?Sized> Sync for ArenaSecret<'_, T> {}
+
+impl<T: ?Sized> ArenaSecret<'_, T> {
+    /// Returns the length of the secret in bytes
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the secret is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// **Reveals** the bytes of the secret
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "reveal only in approved modules, see `RevealToken`")
+    )]
+    pub fn reveal_bytes(&self) -> &[u8] {
+        audit::revealed::<T>();
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl ArenaSecret<'_, str> {
+    /// **Reveals** the held string
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "reveal only in approved modules, see `RevealToken`")
+    )]
+    #[allow(deprecated)]
+    pub fn reveal(&self) -> &str {
+        self.reveal_str()
+    }
+
+    /// Returns and **reveal** a `str` reference.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "reveal only in approved modules, see `RevealToken`")
+    )]
+    #[allow(deprecated)]
+    pub fn reveal_str(&self) -> &str {
+        // only copied from a `str` in `alloc_str`
+        unsafe { str::from_utf8_unchecked(self.reveal_bytes()) }
+    }
+}
+
+impl ArenaSecret<'_, [u8]> {
+    /// **Reveals** the held bytes
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "reveal only in approved modules, see `RevealToken`")
+    )]
+    #[allow(deprecated)]
+    pub fn reveal(&self) -> &[u8] {
+        self.reveal_bytes()
+    }
+}
+
+impl<T: ?Sized> Drop for ArenaSecret<'_, T> {
+    fn drop(&mut self) {
+        unsafe { wipe(self.ptr.as_ptr(), self.len) };
+        if let Some(index) = self.chunk {
+            self.arena.release(index);
+        }
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for ArenaSecret<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("ArenaSecret(...)")
+    }
+}
@@ -0,0 +1,66 @@
+//! Helpers for fixed-size byte arrays, such as symmetric keys
+
+use core::fmt;
+
+use super::Secret;
+
+/// Error returned when a slice does not have the length of the target array
+///
+/// Only contains the expected and the actual length, never any of the bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LengthError {
+    expected: usize,
+    actual: usize,
+}
+
+impl LengthError {
+    /// Returns the length of the target array
+    #[inline]
+    pub fn expected(&self) -> usize {
+        self.expected
+    }
+
+    /// Returns the length of the given slice
+    #[inline]
+    pub fn actual(&self) -> usize {
+        self.actual
+    }
+}
+
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "a confidential value has a length of {} bytes, expected {}",
+            self.actual, self.expected
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LengthError {}
+
+impl<const N: usize> Secret<[u8; N]> {
+    /// Creates a new secret by copying a slice of exactly `N` bytes
+    #[inline]
+    pub fn from_slice(bytes: &[u8]) -> Result<Secret<[u8; N]>, LengthError> {
+        if bytes.len() != N {
+            return Err(LengthError {
+                expected: N,
+                actual: bytes.len(),
+            });
+        }
+
+        let mut secret = Secret::wrap([0; N]);
+        secret.1.copy_from_slice(bytes);
+        Ok(secret)
+    }
+}
+
+impl<const N: usize, P> Secret<[u8; N], P> {
+    /// Returns the held bytes as a slice, wrapped in a secret
+    #[inline]
+    pub fn as_bytes(&self) -> Secret<&[u8], P> {
+        Secret::wrap(&self.1[..])
+    }
+}
@@ -0,0 +1,122 @@
+//! Support code for `assert_secret_eq!` and `assert_secret_ne!`, also used by `secret_format!`
+
+use core::any::type_name;
+use core::fmt;
+
+use super::Secret;
+
+/// Asserts that a secret is equal to another secret or a plain value
+///
+/// Works like `assert_eq!`, but the panic message on failure only contains the types of both
+/// operands, never their values. Either operand can be a `Secret` or a plain value:
+///
+/// ```rust
+/// use sec::{assert_secret_eq, Secret};
+///
+/// let token = Secret::new("THIS-SHOULD-BE-SECRET".to_owned());
+///
+/// assert_secret_eq!(token, "THIS-SHOULD-BE-SECRET");
+/// assert_secret_eq!(token, Secret::new("THIS-SHOULD-BE-SECRET".to_owned()));
+/// ```
+///
+/// An additional message can be passed like with `assert_eq!`. Note that it is printed as is.
+#[macro_export]
+macro_rules! assert_secret_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_secret_eq!(@check ==, $left, $right, None)
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        $crate::assert_secret_eq!(@check ==, $left, $right, Some(format_args!($($arg)+)))
+    };
+    (@check $op:tt, $left:expr, $right:expr, $args:expr) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                #[allow(unused_imports)]
+                use $crate::assert::{RevealPlain as _, RevealSecret as _};
+
+                if !((&$crate::assert::Operand(left)).reveal_operand()
+                    $op (&$crate::assert::Operand(right)).reveal_operand())
+                {
+                    $crate::assert::failed(stringify!($op), left, right, $args);
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that a secret is not equal to another secret or a plain value
+///
+/// See `assert_secret_eq!`.
+#[macro_export]
+macro_rules! assert_secret_ne {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_secret_eq!(@check !=, $left, $right, None)
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        $crate::assert_secret_eq!(@check !=, $left, $right, Some(format_args!($($arg)+)))
+    };
+}
+
+// Method resolution prefers `RevealSecret::reveal_operand` on `Operand<Secret<T>>` over
+// `RevealPlain::reveal_operand`, which needs one more autoref, so secrets are unwrapped while plain
+// values are compared as they are.
+
+#[doc(hidden)]
+pub struct Operand<'a, T: ?Sized>(pub &'a T);
+
+#[doc(hidden)]
+pub trait RevealSecret {
+    type Target: ?Sized;
+
+    fn reveal_operand(&self) -> &Self::Target;
+}
+
+impl<T: ?Sized, P> RevealSecret for Operand<'_, Secret<T, P>> {
+    type Target = T;
+
+    #[inline]
+    fn reveal_operand(&self) -> &T {
+        &self.0 .1
+    }
+}
+
+#[doc(hidden)]
+pub trait RevealPlain {
+    type Target: ?Sized;
+
+    fn reveal_operand(&self) -> &Self::Target;
+}
+
+impl<T: ?Sized> RevealPlain for &Operand<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn reveal_operand(&self) -> &T {
+        self.0
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn failed<L: ?Sized, R: ?Sized>(
+    op: &str,
+    _left: &L,
+    _right: &R,
+    args: Option<fmt::Arguments>,
+) -> ! {
+    match args {
+        Some(args) => panic!(
+            "assertion `left {} right` failed: {}\n  left: <{}>\n right: <{}>",
+            op,
+            args,
+            type_name::<L>(),
+            type_name::<R>()
+        ),
+        None => panic!(
+            "assertion `left {} right` failed\n  left: <{}>\n right: <{}>",
+            op,
+            type_name::<L>(),
+            type_name::<R>()
+        ),
+    }
+}
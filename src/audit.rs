@@ -0,0 +1,63 @@
+#[cfg(feature = "audit")]
+use core::any::type_name;
+#[cfg(feature = "audit")]
+use core::panic::Location;
+#[cfg(feature = "audit")]
+use core::sync::atomic::{AtomicPtr, Ordering};
+#[cfg(feature = "audit")]
+use core::{mem, ptr};
+
+/// Function called whenever a secret is revealed
+///
+/// Receives the location of the call that revealed the secret and the name of the secret's type,
+/// never the value itself.
+#[cfg(feature = "audit")]
+pub type RevealHook = fn(&'static Location<'static>, &str);
+
+/// The installed `RevealHook`, cast to a pointer, or null if none is installed
+#[cfg(feature = "audit")]
+static HOOK: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Installs a process-global hook, called whenever a secret is revealed
+///
+/// Every accessor handing out the held value is reported, except `Secret::reveal_const`, which is a
+/// `const fn` and cannot call the hook.
+///
+/// Replaces any previously installed hook. Hooks should be cheap and must not panic, as they run
+/// on every reveal.
+#[cfg(feature = "audit")]
+#[inline]
+pub fn set_reveal_hook(hook: RevealHook) {
+    HOOK.store(hook as *mut (), Ordering::Relaxed);
+}
+
+/// Removes the installed reveal hook, if any
+#[cfg(feature = "audit")]
+#[inline]
+pub fn clear_reveal_hook() {
+    HOOK.store(ptr::null_mut(), Ordering::Relaxed);
+}
+
+/// Reports a reveal of a value of type `T` to the installed hook and the `stats` counters
+#[cfg(any(feature = "audit", feature = "stats"))]
+#[inline]
+#[cfg_attr(feature = "audit", track_caller)]
+pub(crate) fn revealed<T: ?Sized>() {
+    #[cfg(feature = "stats")]
+    super::stats::revealed::<T>();
+
+    #[cfg(feature = "audit")]
+    {
+        let hook = HOOK.load(Ordering::Relaxed);
+        if !hook.is_null() {
+            // SAFETY: the only non-null values stored in `HOOK` are `RevealHook`s cast to pointers
+            let hook = unsafe { mem::transmute::<*mut (), RevealHook>(hook) };
+            hook(Location::caller(), type_name::<T>());
+        }
+    }
+}
+
+/// Does nothing, reveals are only reported with the `audit` or `stats` features
+#[cfg(not(any(feature = "audit", feature = "stats")))]
+#[inline(always)]
+pub(crate) const fn revealed<T: ?Sized>() {}
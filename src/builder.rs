@@ -0,0 +1,121 @@
+use alloc::string::String;
+use core::{fmt, mem};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use super::Secret;
+
+/// Assembles a `Secret<String>` from plain and secret fragments
+///
+/// Composite secrets such as `user:password` pairs or connection strings can be built without
+/// revealing their parts into temporaries:
+///
+/// ```rust
+/// use sec::{Secret, SecretBuilder};
+///
+/// let password = Secret::new("THIS-SHOULD-BE-SECRET".to_owned());
+///
+/// let mut builder = SecretBuilder::new();
+/// builder.push_str("alice:").push_secret(&password);
+/// let credentials = builder.build();
+///
+/// assert_eq!(credentials.reveal(), "alice:THIS-SHOULD-BE-SECRET");
+/// ```
+///
+/// With the `zeroize` feature, the buffer is wiped when the builder is dropped, and whenever it
+/// has to grow, the old allocation is wiped before it is freed.
+pub struct SecretBuilder {
+    buf: String,
+}
+
+impl SecretBuilder {
+    /// Creates an empty builder
+    #[inline]
+    pub const fn new() -> SecretBuilder {
+        SecretBuilder { buf: String::new() }
+    }
+
+    /// Creates an empty builder with room for `capacity` bytes
+    ///
+    /// Reserving the final size up front avoids reallocations, and thus copies of the fragments.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> SecretBuilder {
+        SecretBuilder {
+            buf: String::with_capacity(capacity),
+        }
+    }
+
+    /// Appends a plain string
+    #[inline]
+    pub fn push_str(&mut self, s: &str) -> &mut SecretBuilder {
+        self.reserve(s.len());
+        self.buf.push_str(s);
+        self
+    }
+
+    /// Appends the value of a secret string
+    #[inline]
+    pub fn push_secret<P>(&mut self, s: &Secret<String, P>) -> &mut SecretBuilder {
+        self.push_str(&s.1)
+    }
+
+    /// Appends the value of a secret string slice
+    #[inline]
+    pub fn push_secret_str<P>(&mut self, s: Secret<&str, P>) -> &mut SecretBuilder {
+        self.push_str(s.1)
+    }
+
+    /// Returns the assembled secret
+    #[inline]
+    pub fn build(mut self) -> Secret<String> {
+        Secret::wrap(mem::take(&mut self.buf))
+    }
+
+    /// Makes room for `additional` more bytes, wiping the old allocation if it has to be replaced
+    #[cfg(feature = "zeroize")]
+    fn reserve(&mut self, additional: usize) {
+        if self.buf.capacity() - self.buf.len() >= additional {
+            return;
+        }
+
+        let required = self
+            .buf
+            .len()
+            .checked_add(additional)
+            .expect("capacity overflow");
+        let mut buf = String::with_capacity(required.max(self.buf.capacity() * 2));
+        buf.push_str(&self.buf);
+
+        mem::swap(&mut self.buf, &mut buf);
+        buf.zeroize();
+    }
+
+    #[cfg(not(feature = "zeroize"))]
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        self.buf.reserve(additional);
+    }
+}
+
+impl Default for SecretBuilder {
+    #[inline]
+    fn default() -> SecretBuilder {
+        SecretBuilder::new()
+    }
+}
+
+impl fmt::Debug for SecretBuilder {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SecretBuilder(...)")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for SecretBuilder {
+    #[inline]
+    fn drop(&mut self) {
+        self.buf.zeroize();
+    }
+}
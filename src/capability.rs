@@ -0,0 +1,88 @@
+use super::{audit, Secret};
+
+/// Permission to reveal secrets, required by `reveal_with` and its variants
+///
+/// With the `capability` feature, the ordinary `reveal*` methods of `Secret` and the reveal
+/// accessors of the other types of this crate are deprecated, so `#![deny(deprecated)]` or
+/// `-D warnings` rejects any module revealing secrets without a token.
+/// Tokens are issued with the `issue_reveal_token!` macro, which should only appear in modules
+/// approved to reveal secrets, and is easy to find with `grep`:
+///
+/// ```rust
+/// use sec::{RevealToken, Secret};
+///
+/// fn connect(password: &Secret<String>, token: &RevealToken) -> usize {
+///     password.reveal_with(token).len()
+/// }
+///
+/// let token = sec::issue_reveal_token!();
+/// assert_eq!(connect(&Secret::new("hunter2".to_owned()), &token), 7);
+/// ```
+///
+/// Revealing a secret without a token is rejected under `#![deny(deprecated)]`:
+///
+/// ```compile_fail
+/// #![deny(deprecated)]
+///
+/// let password = sec::Secret::new("hunter2".to_owned());
+/// assert_eq!(password.reveal(), "hunter2");
+/// ```
+///
+/// and a token cannot be constructed outside of `issue_reveal_token!`:
+///
+/// ```compile_fail
+/// let token = sec::RevealToken { _private: () };
+/// ```
+///
+/// A `RevealToken` cannot be cloned, but references to it can be passed around freely.
+#[derive(Debug)]
+pub struct RevealToken {
+    _private: (),
+}
+
+impl RevealToken {
+    #[doc(hidden)]
+    #[inline]
+    pub const fn __issue() -> RevealToken {
+        RevealToken { _private: () }
+    }
+}
+
+/// Issues a `RevealToken`
+///
+/// Only to be used in modules approved to reveal secrets, see `RevealToken`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! issue_reveal_token {
+    () => {
+        $crate::RevealToken::__issue()
+    };
+}
+
+impl<T: ?Sized, P> Secret<T, P> {
+    /// **Reveals** the held value by returning a reference, given a `RevealToken`
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn reveal_with(&self, _token: &RevealToken) -> &T {
+        audit::revealed::<T>();
+        &self.1
+    }
+
+    /// **Reveals** the held value by returning a mutable reference, given a `RevealToken`
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn reveal_mut_with(&mut self, _token: &RevealToken) -> &mut T {
+        audit::revealed::<T>();
+        &mut self.1
+    }
+}
+
+impl<T, P> Secret<T, P> {
+    /// **Reveals** the held value by unwrapping, given a `RevealToken`
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn reveal_into_with(self, _token: &RevealToken) -> T {
+        audit::revealed::<T>();
+        self.1
+    }
+}
@@ -0,0 +1,82 @@
+//! Comparisons against plain values, enabled by the `cmp-plain` feature
+//!
+//! These are opt-in because `secret == value` reads exactly like a comparison of two plain values,
+//! which makes it easy to lose track of which side is secret. Like the `PartialEq` implementation
+//! for two secrets, none of these comparisons are constant-time.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use super::Secret;
+
+impl<T: PartialEq + ?Sized, P> PartialEq<T> for Secret<T, P> {
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        self.1.eq(other)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> PartialEq<str> for Secret<String, P> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.1 == other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, P> PartialEq<&'a str> for Secret<String, P> {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self.1 == *other
+    }
+}
+
+// The generic `impl<T> PartialEq<Secret<T>> for T` is ruled out by the orphan rules, so the reverse
+// comparisons are implemented for common types only.
+
+#[cfg(feature = "alloc")]
+impl<P> PartialEq<Secret<String, P>> for String {
+    #[inline]
+    fn eq(&self, other: &Secret<String, P>) -> bool {
+        *self == other.1
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> PartialEq<Secret<String, P>> for str {
+    #[inline]
+    fn eq(&self, other: &Secret<String, P>) -> bool {
+        self == other.1
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> PartialEq<Secret<String, P>> for &str {
+    #[inline]
+    fn eq(&self, other: &Secret<String, P>) -> bool {
+        *self == other.1
+    }
+}
+
+impl<'b, P> PartialEq<Secret<&'b str, P>> for &str {
+    #[inline]
+    fn eq(&self, other: &Secret<&'b str, P>) -> bool {
+        *self == other.1
+    }
+}
+
+macro_rules! impl_reverse_eq {
+    ($($ty:ty),*) => {
+        $(
+            impl<P> PartialEq<Secret<$ty, P>> for $ty {
+                #[inline]
+                fn eq(&self, other: &Secret<$ty, P>) -> bool {
+                    *self == other.1
+                }
+            }
+        )*
+    };
+}
+
+impl_reverse_eq!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool, char);
@@ -0,0 +1,71 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use super::Secret;
+
+impl<T, P> Secret<Vec<T>, P> {
+    /// Returns the element at `index`, wrapped in a secret
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<Secret<&T, P>> {
+        self.1.get(index).map(Secret::wrap)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S, P> Secret<HashMap<K, V, S>, P>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    /// Returns the value stored for `key`, wrapped in a secret
+    ///
+    /// Only the requested entry is exposed, not the whole map.
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<Secret<&V, P>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.1.get(key).map(Secret::wrap)
+    }
+
+    /// Returns whether a value is stored for `key`
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.1.contains_key(key)
+    }
+}
+
+impl<K: Ord, V, P> Secret<BTreeMap<K, V>, P> {
+    /// Returns the value stored for `key`, wrapped in a secret
+    ///
+    /// Only the requested entry is exposed, not the whole map.
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<Secret<&V, P>>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.1.get(key).map(Secret::wrap)
+    }
+
+    /// Returns whether a value is stored for `key`
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.1.contains_key(key)
+    }
+}
@@ -0,0 +1,29 @@
+use core::fmt;
+
+use super::redact::RedactedSource;
+
+/// Extension trait for attaching context to an error while redacting it
+///
+/// This is the redacting counterpart of `anyhow::Context`.
+pub trait SecContextExt<T> {
+    /// Wraps the error, if any, in `context`, replacing the error and all of its sources
+    ///
+    /// The length of the error chain is kept, but every error below `context` is displayed as
+    /// "error while processing a confidential value".
+    fn context_redacted<C>(self, context: C) -> anyhow::Result<T>
+    where
+        C: fmt::Display + Send + Sync + 'static;
+}
+
+impl<T, E: Into<anyhow::Error>> SecContextExt<T> for Result<T, E> {
+    #[inline]
+    fn context_redacted<C>(self, context: C) -> anyhow::Result<T>
+    where
+        C: fmt::Display + Send + Sync + 'static,
+    {
+        self.map_err(|err| {
+            let err: anyhow::Error = err.into();
+            anyhow::Error::new(RedactedSource::new(&*err)).context(context)
+        })
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::convert::TryFrom;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use super::Secret;
+#[cfg(feature = "std")]
+use super::SecretError;
+
+mod private {
+    pub trait Sealed {}
+
+    impl<T: ?Sized> Sealed for T {}
+}
+
+/// Extension trait for wrapping any value in a `Secret` with a postfix method
+///
+/// Since it is implemented for every type, its methods may clash with methods of the same name on
+/// other types in scope. It is therefore not meant to be glob-imported along with other traits, but
+/// imported explicitly or through `sec::prelude`:
+///
+/// ```rust
+/// use sec::prelude::*;
+///
+/// let token = "THIS-SHOULD-BE-SECRET".to_owned().into_secret();
+/// assert_eq!(token.reveal(), "THIS-SHOULD-BE-SECRET");
+/// ```
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait IntoSecret: private::Sealed {
+    /// Wraps `self` in a `Secret`
+    #[inline]
+    fn into_secret(self) -> Secret<Self>
+    where
+        Self: Sized,
+    {
+        Secret::wrap(self)
+    }
+
+    /// Wraps a reference to `self` in a `Secret`
+    #[inline]
+    fn as_secret(&self) -> Secret<&Self> {
+        Secret::wrap(self)
+    }
+}
+
+impl<T: ?Sized> IntoSecret for T {}
+
+impl<T, P> Secret<T, P> {
+    /// Converts the held value into another type through `From`, keeping it secret
+    #[inline]
+    pub fn convert<U: From<T>>(self) -> Secret<U, P> {
+        Secret::wrap(U::from(self.1))
+    }
+
+    /// Converts the held value into another type through `TryFrom`, keeping it secret
+    ///
+    /// The error is returned as-is. Most conversion errors, such as `TryFromIntError`, carry no
+    /// data, but some embed the input and leak the secret; use `try_convert_redacted` for those.
+    #[inline]
+    pub fn try_convert<U: TryFrom<T>>(self) -> Result<Secret<U, P>, U::Error> {
+        U::try_from(self.1).map(Secret::wrap)
+    }
+
+    /// Converts the held value into another type through `TryFrom`, redacting errors
+    ///
+    /// Like `try_convert`, but the error is wrapped in a `SecretError`.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn try_convert_redacted<U>(self) -> Result<Secret<U, P>, SecretError<U::Error>>
+    where
+        U: TryFrom<T>,
+        U::Error: Error,
+    {
+        self.try_convert().map_err(SecretError::new)
+    }
+}
+
+// A generic `impl<T, U: From<T>> From<Secret<T>> for Secret<U>` conflicts with the reflexive
+// `From<T> for T`, so only common conversions are implemented.
+
+#[cfg(feature = "alloc")]
+impl<P> From<Secret<&str, P>> for Secret<String, P> {
+    #[inline]
+    fn from(secret: Secret<&str, P>) -> Secret<String, P> {
+        secret.convert()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> From<Secret<&[u8], P>> for Secret<Vec<u8>, P> {
+    #[inline]
+    fn from(secret: Secret<&[u8], P>) -> Secret<Vec<u8>, P> {
+        secret.convert()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> From<Secret<String, P>> for Secret<Vec<u8>, P> {
+    #[inline]
+    fn from(secret: Secret<String, P>) -> Secret<Vec<u8>, P> {
+        secret.convert()
+    }
+}
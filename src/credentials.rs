@@ -0,0 +1,142 @@
+use alloc::string::String;
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::de::{self, IgnoredAny, MapAccess, SeqAccess, Visitor};
+#[cfg(feature = "serde")]
+use serde::ser::SerializeStruct;
+
+use super::Secret;
+
+/// A public identity paired with its secret, e.g. a username and password or a key id and key
+///
+/// Only the secret is protected: `Debug` shows the id, but redacts the secret. Equality compares
+/// both parts, the secret with the (non-constant-time) `PartialEq` of `Secret`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Credentials<S = String> {
+    /// The identity, e.g. a username or key id
+    pub id: String,
+    /// The secret belonging to `id`
+    pub secret: Secret<S>,
+}
+
+impl<S> Credentials<S> {
+    /// Creates new credentials from an id and a plain secret value
+    #[inline]
+    pub fn new<I: Into<String>>(id: I, secret: S) -> Credentials<S> {
+        Credentials {
+            id: id.into(),
+            secret: Secret::wrap(secret),
+        }
+    }
+
+    /// Creates new credentials from an id and an already wrapped secret
+    #[inline]
+    pub fn from_secret<I: Into<String>>(id: I, secret: Secret<S>) -> Credentials<S> {
+        Credentials {
+            id: id.into(),
+            secret,
+        }
+    }
+}
+
+impl Credentials<String> {
+    /// Splits a secret of the form `id:secret` at the first colon
+    ///
+    /// This is the format of HTTP basic auth and many `user:pass` environment variables. The id
+    /// may not contain a colon, the secret may. Returns `None` if there is no colon.
+    pub fn from_colon_pair(pair: Secret<String>) -> Option<Credentials<String>> {
+        let (id, secret) = pair.1.split_once(':')?;
+
+        Some(Credentials {
+            id: String::from(id),
+            secret: Secret::wrap(String::from(secret)),
+        })
+    }
+}
+
+impl<S> fmt::Debug for Credentials<S>
+where
+    Secret<S>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("id", &self.id)
+            .field("secret", &self.secret)
+            .finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<S: serde::Serialize> serde::Serialize for Credentials<S> {
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("Credentials", 2)?;
+        state.serialize_field("id", &self.id)?;
+        state.serialize_field("secret", &self.secret)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, S: serde::Deserialize<'de>> serde::Deserialize<'de> for Credentials<S> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct CredentialsVisitor<S>(core::marker::PhantomData<S>);
+
+        impl<'de, S: serde::Deserialize<'de>> Visitor<'de> for CredentialsVisitor<S> {
+            type Value = Credentials<S>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("credentials with an id and a secret")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let id = seq
+                    .next_element()?
+                    .ok_or_else(|| <A::Error as de::Error>::invalid_length(0, &self))?;
+                // the `Deserialize` implementation of `Secret` already scrubs its errors
+                let secret = seq
+                    .next_element()?
+                    .ok_or_else(|| <A::Error as de::Error>::invalid_length(1, &self))?;
+
+                Ok(Credentials { id, secret })
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut id = None;
+                let mut secret = None;
+
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "id" if id.is_some() => return Err(de::Error::duplicate_field("id")),
+                        "id" => id = Some(map.next_value()?),
+                        "secret" if secret.is_some() => {
+                            return Err(de::Error::duplicate_field("secret"))
+                        }
+                        "secret" => secret = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+
+                Ok(Credentials {
+                    id: id.ok_or_else(|| <A::Error as de::Error>::missing_field("id"))?,
+                    secret: secret
+                        .ok_or_else(|| <A::Error as de::Error>::missing_field("secret"))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Credentials",
+            &["id", "secret"],
+            CredentialsVisitor(core::marker::PhantomData),
+        )
+    }
+}
@@ -0,0 +1,130 @@
+use core::hint::black_box;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "subtle")]
+use subtle::{Choice, ConstantTimeEq};
+
+use super::Secret;
+
+/// Compares two byte slices in constant time with respect to their contents
+///
+/// Slices of different lengths compare unequal immediately.
+#[inline(never)]
+pub(crate) fn eq_bytes(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    // `black_box` keeps the optimizer from turning the loop into an early-exit comparison
+    let mut diff = 0u8;
+    for (x, y) in a.iter().zip(b) {
+        diff = black_box(diff | (x ^ y));
+    }
+
+    diff == 0
+}
+
+#[cfg(feature = "alloc")]
+impl<P> Secret<String, P> {
+    /// Checks whether `candidate` matches the held value, in constant time
+    ///
+    /// The comparison time does not depend on the contents of either value, making this suitable
+    /// for checking user-supplied input such as API keys. The length of the secret is not
+    /// considered confidential: if the lengths differ, `false` is returned immediately.
+    #[inline]
+    pub fn verify(&self, candidate: &str) -> bool {
+        eq_bytes(self.1.as_bytes(), candidate.as_bytes())
+    }
+}
+
+impl<P> Secret<&str, P> {
+    /// Checks whether `candidate` matches the held value, in constant time
+    ///
+    /// See `Secret<String>::verify`.
+    #[inline]
+    pub fn verify(&self, candidate: &str) -> bool {
+        eq_bytes(self.1.as_bytes(), candidate.as_bytes())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> Secret<Vec<u8>, P> {
+    /// Checks whether `candidate` matches the held value, in constant time
+    ///
+    /// See `Secret<String>::verify`.
+    #[inline]
+    pub fn verify(&self, candidate: &[u8]) -> bool {
+        eq_bytes(&self.1, candidate)
+    }
+}
+
+impl<P> Secret<&[u8], P> {
+    /// Checks whether `candidate` matches the held value, in constant time
+    ///
+    /// See `Secret<String>::verify`.
+    #[inline]
+    pub fn verify(&self, candidate: &[u8]) -> bool {
+        eq_bytes(self.1, candidate)
+    }
+}
+
+impl<const N: usize, P> Secret<[u8; N], P> {
+    /// Checks whether `candidate` matches the held value, in constant time
+    ///
+    /// See `Secret<String>::verify`.
+    #[inline]
+    pub fn verify(&self, candidate: &[u8]) -> bool {
+        eq_bytes(&self.1, candidate)
+    }
+}
+
+/// Compares two secrets in constant time
+///
+/// Unlike `PartialEq`, the comparison does not short-circuit on the first differing element and
+/// therefore does not leak the length of a common prefix through timing.
+#[cfg(feature = "subtle")]
+impl<T: ConstantTimeEq + ?Sized, P> ConstantTimeEq for Secret<T, P> {
+    #[inline]
+    fn ct_eq(&self, other: &Secret<T, P>) -> Choice {
+        self.1.ct_eq(&other.1)
+    }
+}
+
+// `String` and `Vec<u8>` do not implement `ConstantTimeEq`, and coherence rules prevent adding trait
+// impls for them alongside the generic one. Inherent methods with the same name are provided
+// instead, which take precedence in method call syntax.
+
+#[cfg(all(feature = "alloc", feature = "subtle"))]
+impl<P> Secret<String, P> {
+    /// Compares the byte representation of two secrets in constant time
+    ///
+    /// The length of the secrets is not considered confidential: secrets of different lengths
+    /// compare unequal immediately.
+    #[inline]
+    pub fn ct_eq(&self, other: &Secret<String, P>) -> Choice {
+        self.1.as_bytes().ct_eq(other.1.as_bytes())
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "subtle"))]
+impl<P> Secret<Vec<u8>, P> {
+    /// Compares two secrets in constant time
+    ///
+    /// See `Secret<String>::ct_eq`.
+    #[inline]
+    pub fn ct_eq(&self, other: &Secret<Vec<u8>, P>) -> Choice {
+        self.1.as_slice().ct_eq(other.1.as_slice())
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P> Secret<&str, P> {
+    /// Compares the byte representation of two secrets in constant time
+    ///
+    /// See `Secret<String>::ct_eq`.
+    #[inline]
+    pub fn ct_eq(&self, other: &Secret<&str, P>) -> Choice {
+        self.1.as_bytes().ct_eq(other.1.as_bytes())
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+use hkdf::Hkdf;
+use sha2::Sha256;
+
+use super::Secret;
+
+/// Maximum length of a key derived with HKDF-SHA256, in bytes
+const MAX_LEN: usize = 255 * 32;
+
+impl<const N: usize, P> Secret<[u8; N], P> {
+    /// Derives a key of `M` bytes from this master key with HKDF-SHA256
+    ///
+    /// `info` names the purpose of the key, e.g. `b"cookie-signing"`, so that keys for different
+    /// purposes are independent. `salt` may be empty. Neither the master key nor the derived key
+    /// leave the crate outside of a `Secret`, although the internal state of HKDF is not wiped.
+    ///
+    /// ```rust
+    /// use sec::Secret;
+    ///
+    /// let master: Secret<[u8; 32]> = Secret::new([0x42; 32]);
+    /// let cookie_key: Secret<[u8; 32]> = master.derive_key(b"", b"cookie-signing");
+    /// let db_key: Secret<[u8; 32]> = master.derive_key(b"", b"db-encryption");
+    /// assert_ne!(cookie_key, db_key);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` exceeds 8160, the maximum output length of HKDF-SHA256.
+    pub fn derive_key<const M: usize>(&self, salt: &[u8], info: &[u8]) -> Secret<[u8; M], P> {
+        let mut key = Secret::wrap([0; M]);
+        expand(&self.1, salt, info, &mut key.1);
+        key
+    }
+
+    /// Derives a key of `len` bytes from this master key with HKDF-SHA256
+    ///
+    /// See `derive_key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` exceeds 8160, the maximum output length of HKDF-SHA256.
+    #[cfg(feature = "alloc")]
+    pub fn derive_key_bytes(&self, salt: &[u8], info: &[u8], len: usize) -> Secret<Vec<u8>, P> {
+        let mut key = Secret::wrap(vec![0; len]);
+        expand(&self.1, salt, info, &mut key.1);
+        key
+    }
+}
+
+/// Fills `okm` with key material derived from `ikm`
+fn expand(ikm: &[u8], salt: &[u8], info: &[u8], okm: &mut [u8]) {
+    assert!(
+        okm.len() <= MAX_LEN,
+        "cannot derive more than {} bytes with HKDF-SHA256",
+        MAX_LEN
+    );
+    Hkdf::<Sha256>::new(Some(salt), ikm)
+        .expand(info, okm)
+        .expect("length was checked");
+}
@@ -0,0 +1,211 @@
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+#[cfg(all(feature = "alloc", feature = "base64"))]
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig, STANDARD};
+#[cfg(all(feature = "alloc", feature = "base64"))]
+use base64::engine::DecodePaddingMode;
+#[cfg(all(feature = "alloc", feature = "base64"))]
+use base64::{alphabet, Engine};
+
+use super::Secret;
+
+/// URL-safe engine, encodes without padding but accepts padded input
+#[cfg(all(feature = "alloc", feature = "base64"))]
+const URL_SAFE: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::URL_SAFE,
+    GeneralPurposeConfig::new()
+        .with_encode_padding(false)
+        .with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// Error returned when decoding a secret fails
+///
+/// A `DecodeError` never contains the input or any part of it, at most the offset at which
+/// decoding failed.
+#[cfg(all(feature = "alloc", feature = "base64"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// Encountered a character outside of the alphabet
+    InvalidByte { offset: usize },
+    /// The length of the input is invalid
+    InvalidLength,
+    /// The last symbol has trailing bits set, which would be discarded
+    InvalidLastSymbol { offset: usize },
+    /// Padding is missing or malformed
+    InvalidPadding,
+}
+
+#[cfg(all(feature = "alloc", feature = "base64"))]
+impl DecodeError {
+    #[inline]
+    fn from_base64(err: base64::DecodeError) -> DecodeError {
+        match err {
+            base64::DecodeError::InvalidByte(offset, _) => DecodeError::InvalidByte { offset },
+            base64::DecodeError::InvalidLength(_) => DecodeError::InvalidLength,
+            base64::DecodeError::InvalidLastSymbol(offset, _) => {
+                DecodeError::InvalidLastSymbol { offset }
+            }
+            base64::DecodeError::InvalidPadding => DecodeError::InvalidPadding,
+        }
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "base64"))]
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a confidential value could not be decoded: ")?;
+
+        match *self {
+            DecodeError::InvalidByte { offset } => {
+                write!(f, "invalid character at offset {}", offset)
+            }
+            DecodeError::InvalidLength => f.write_str("invalid length"),
+            DecodeError::InvalidLastSymbol { offset } => {
+                write!(f, "invalid last symbol at offset {}", offset)
+            }
+            DecodeError::InvalidPadding => f.write_str("invalid padding"),
+        }
+    }
+}
+
+#[cfg(all(feature = "std", feature = "base64"))]
+impl std::error::Error for DecodeError {}
+
+#[cfg(all(feature = "alloc", feature = "base64"))]
+#[inline]
+fn decode_base64<E: Engine, P>(engine: &E, input: &str) -> Result<Secret<Vec<u8>, P>, DecodeError> {
+    engine
+        .decode(input)
+        .map(Secret::wrap)
+        .map_err(DecodeError::from_base64)
+}
+
+#[cfg(all(feature = "alloc", feature = "base64"))]
+impl<P> Secret<Vec<u8>, P> {
+    /// Encodes the held value as base64, using the standard alphabet with padding
+    #[inline]
+    pub fn to_base64(&self) -> Secret<String, P> {
+        Secret::wrap(STANDARD.encode(&self.1))
+    }
+
+    /// Encodes the held value as base64, using the URL-safe alphabet without padding
+    #[inline]
+    pub fn to_base64_url(&self) -> Secret<String, P> {
+        Secret::wrap(URL_SAFE.encode(&self.1))
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "base64"))]
+impl<P> Secret<String, P> {
+    /// Decodes the held value from base64, using the standard alphabet with padding
+    ///
+    /// The decoded bytes are written directly into the returned secret. On failure, the error
+    /// contains at most the offset of the offending character.
+    #[inline]
+    pub fn decode_base64(&self) -> Result<Secret<Vec<u8>, P>, DecodeError> {
+        decode_base64(&STANDARD, &self.1)
+    }
+
+    /// Decodes the held value from base64, using the URL-safe alphabet
+    ///
+    /// Padding is optional. See `decode_base64`.
+    #[inline]
+    pub fn decode_base64_url(&self) -> Result<Secret<Vec<u8>, P>, DecodeError> {
+        decode_base64(&URL_SAFE, &self.1)
+    }
+}
+
+/// Error returned when decoding a hex-encoded secret fails
+///
+/// A `HexError` never contains the input or any part of it, at most the offset at which decoding
+/// failed.
+#[cfg(feature = "hex")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HexError {
+    /// Encountered a character that is not a hex digit
+    InvalidCharacter { offset: usize },
+    /// The input has an odd number of characters
+    OddLength,
+    /// The decoded length does not match the size of the target
+    InvalidLength,
+}
+
+#[cfg(feature = "hex")]
+impl HexError {
+    #[inline]
+    fn from_hex(err: hex::FromHexError) -> HexError {
+        match err {
+            hex::FromHexError::InvalidHexCharacter { index, .. } => {
+                HexError::InvalidCharacter { offset: index }
+            }
+            hex::FromHexError::OddLength => HexError::OddLength,
+            hex::FromHexError::InvalidStringLength => HexError::InvalidLength,
+        }
+    }
+}
+
+#[cfg(feature = "hex")]
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a confidential value could not be decoded from hex: ")?;
+
+        match *self {
+            HexError::InvalidCharacter { offset } => {
+                write!(f, "invalid character at offset {}", offset)
+            }
+            HexError::OddLength => f.write_str("odd number of characters"),
+            HexError::InvalidLength => f.write_str("invalid length"),
+        }
+    }
+}
+
+#[cfg(all(feature = "std", feature = "hex"))]
+impl std::error::Error for HexError {}
+
+#[cfg(all(feature = "alloc", feature = "hex"))]
+impl<P> Secret<Vec<u8>, P> {
+    /// Encodes the held value as lowercase hex
+    #[inline]
+    pub fn to_hex(&self) -> Secret<String, P> {
+        Secret::wrap(hex::encode(&self.1))
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "hex"))]
+impl<P> Secret<String, P> {
+    /// Decodes the held value from hex
+    ///
+    /// Both lowercase and uppercase digits are accepted. On failure, the error contains at most the
+    /// offset of the offending character.
+    #[inline]
+    pub fn decode_hex(&self) -> Result<Secret<Vec<u8>, P>, HexError> {
+        hex::decode(&self.1)
+            .map(Secret::wrap)
+            .map_err(HexError::from_hex)
+    }
+}
+
+#[cfg(feature = "hex")]
+impl<const N: usize> Secret<[u8; N]> {
+    /// Creates a new secret by decoding exactly `N` bytes from hex
+    ///
+    /// The bytes are decoded directly into the secret. Fails with `HexError::InvalidLength` if the
+    /// input does not decode to exactly `N` bytes.
+    #[inline]
+    pub fn from_hex<S: AsRef<[u8]>>(hex: S) -> Result<Secret<[u8; N]>, HexError> {
+        let mut secret = Secret::wrap([0; N]);
+        hex::decode_to_slice(hex, &mut secret.1).map_err(HexError::from_hex)?;
+        Ok(secret)
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "hex"))]
+impl<const N: usize, P> Secret<[u8; N], P> {
+    /// Encodes the held value as lowercase hex
+    #[inline]
+    pub fn to_hex(&self) -> Secret<String, P> {
+        Secret::wrap(hex::encode(self.1.as_slice()))
+    }
+}
@@ -0,0 +1,206 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use super::{Ellipsis, Secret};
+
+/// Length of the random nonce stored with every encrypted secret
+const NONCE_LEN: usize = 12;
+
+/// Key for encrypting secrets in memory, generated randomly and never leaving the process
+pub struct ProcessKey(ChaCha20Poly1305);
+
+impl ProcessKey {
+    /// Generates a new random key
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operating system fails to provide random data.
+    pub fn generate() -> ProcessKey {
+        let key = Secret::<[u8; 32]>::random();
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(&key.1));
+        #[cfg(feature = "zeroize")]
+        {
+            let mut key = key;
+            key.zeroize();
+        }
+        ProcessKey(cipher)
+    }
+
+    /// Returns the key of the process, generating it on first use
+    #[cfg(feature = "std")]
+    pub fn global() -> &'static ProcessKey {
+        static GLOBAL: OnceLock<ProcessKey> = OnceLock::new();
+        GLOBAL.get_or_init(ProcessKey::generate)
+    }
+}
+
+impl fmt::Debug for ProcessKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("ProcessKey(...)")
+    }
+}
+
+/// Error returned when an `EncryptedSecret` cannot be opened
+///
+/// Either the key differs from the one the secret was sealed with, or the ciphertext was modified.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OpenError;
+
+impl fmt::Display for OpenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("could not decrypt secret: wrong key or modified ciphertext")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OpenError {}
+
+/// A secret that is kept encrypted in memory, and only decrypted on access
+///
+/// Long-lived processes holding many credentials can keep them sealed with ChaCha20-Poly1305
+/// under a `ProcessKey`, so that a dump of the process memory shows only ciphertext, apart from the
+/// key and the values currently in use:
+///
+/// ```rust
+/// use sec::{EncryptedSecret, ProcessKey, Secret};
+///
+/// let key = ProcessKey::generate();
+/// let sealed = EncryptedSecret::seal(Secret::new("hunter2".to_owned()), &key);
+///
+/// let opened = sealed.open(&key).unwrap();
+/// assert_eq!(opened.reveal(), "hunter2");
+/// ```
+///
+/// `T` is either `Vec<u8>` or `String`. Every call to `seal` uses a new random nonce.
+/// Modifications of the ciphertext are detected when opening it. `Debug` shows only the length of
+/// the ciphertext. The placeholder `P` of the sealed secret is kept for the opened one.
+pub struct EncryptedSecret<T = Vec<u8>, P = Ellipsis> {
+    nonce: [u8; NONCE_LEN],
+    pub(crate) ciphertext: Vec<u8>,
+    _marker: PhantomData<(T, P)>,
+}
+
+impl<T, P> EncryptedSecret<T, P> {
+    fn seal_bytes(plaintext: &[u8], key: &ProcessKey) -> EncryptedSecret<T, P> {
+        let nonce = Secret::<[u8; NONCE_LEN]>::random().1;
+        let ciphertext = key
+            .0
+            .encrypt(Nonce::from_slice(&nonce), plaintext)
+            .expect("secret is too large to encrypt");
+
+        EncryptedSecret {
+            nonce,
+            ciphertext,
+            _marker: PhantomData,
+        }
+    }
+
+    fn open_bytes(&self, key: &ProcessKey) -> Result<Vec<u8>, OpenError> {
+        key.0
+            .decrypt(Nonce::from_slice(&self.nonce), self.ciphertext.as_slice())
+            .map_err(|_| OpenError)
+    }
+
+    /// Returns the length of the ciphertext, which exceeds that of the plaintext by 16 bytes
+    #[inline]
+    pub fn ciphertext_len(&self) -> usize {
+        self.ciphertext.len()
+    }
+}
+
+impl<T: private::Plaintext, P> EncryptedSecret<T, P> {
+    /// Encrypts a secret
+    ///
+    /// With the `zeroize` feature, the plaintext is wiped afterwards.
+    pub fn seal(secret: Secret<T, P>, key: &ProcessKey) -> EncryptedSecret<T, P> {
+        let mut plaintext = secret.1;
+        let sealed = EncryptedSecret::seal_bytes(plaintext.as_bytes(), key);
+        plaintext.wipe();
+        sealed
+    }
+
+    /// Decrypts the secret
+    pub fn open(&self, key: &ProcessKey) -> Result<Secret<T, P>, OpenError> {
+        let plaintext = self.open_bytes(key)?;
+        // cannot fail, as only valid values of `T` are sealed into an `EncryptedSecret<T>`
+        T::from_bytes(plaintext).map(Secret::wrap).ok_or(OpenError)
+    }
+}
+
+mod private {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    #[cfg(feature = "zeroize")]
+    use zeroize::Zeroize;
+
+    /// Types of secrets that can be encrypted, `Vec<u8>` and `String`
+    pub trait Plaintext: Sized {
+        fn as_bytes(&self) -> &[u8];
+        fn from_bytes(bytes: Vec<u8>) -> Option<Self>;
+        /// Wipes the value with the `zeroize` feature, does nothing otherwise
+        fn wipe(&mut self);
+    }
+
+    impl Plaintext for Vec<u8> {
+        #[inline]
+        fn as_bytes(&self) -> &[u8] {
+            self
+        }
+
+        #[inline]
+        fn from_bytes(bytes: Vec<u8>) -> Option<Self> {
+            Some(bytes)
+        }
+
+        #[inline]
+        fn wipe(&mut self) {
+            #[cfg(feature = "zeroize")]
+            self.zeroize();
+        }
+    }
+
+    impl Plaintext for String {
+        #[inline]
+        fn as_bytes(&self) -> &[u8] {
+            String::as_bytes(self)
+        }
+
+        #[inline]
+        fn from_bytes(bytes: Vec<u8>) -> Option<Self> {
+            String::from_utf8(bytes).ok()
+        }
+
+        #[inline]
+        fn wipe(&mut self) {
+            #[cfg(feature = "zeroize")]
+            self.zeroize();
+        }
+    }
+}
+
+impl<T, P> Clone for EncryptedSecret<T, P> {
+    fn clone(&self) -> Self {
+        EncryptedSecret {
+            nonce: self.nonce,
+            ciphertext: self.ciphertext.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, P> fmt::Debug for EncryptedSecret<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("EncryptedSecret")
+            .field("ciphertext_len", &self.ciphertext.len())
+            .finish()
+    }
+}
@@ -0,0 +1,393 @@
+//! The unified error type of the fallible APIs of this crate
+
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+#[cfg(feature = "std")]
+use std::path::PathBuf;
+#[cfg(feature = "std")]
+use std::string::{String, ToString};
+
+#[cfg(all(feature = "keyring", feature = "std"))]
+use super::keyring::KeyringError;
+#[cfg(feature = "std")]
+use super::provider::ProviderError;
+#[cfg(feature = "std")]
+use super::source::FileError;
+#[cfg(feature = "std")]
+use super::systemd::CredentialError;
+#[cfg(all(feature = "alloc", feature = "base64"))]
+use super::DecodeError;
+#[cfg(feature = "hex")]
+use super::HexError;
+#[cfg(all(feature = "mlock", any(unix, windows)))]
+use super::LockError;
+#[cfg(feature = "encrypt")]
+use super::OpenError;
+#[cfg(feature = "alloc")]
+use super::Utf8SecretError;
+#[cfg(feature = "std")]
+use super::{EnvError, NulSecretError};
+use super::{LengthError, ParseError};
+
+/// What went wrong while decoding an encoded secret, see `Error::Decode`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecodeKind {
+    /// Encountered a character outside of the alphabet
+    InvalidCharacter,
+    /// The length of the input is invalid
+    InvalidLength,
+    /// The last symbol has trailing bits set, which would be discarded
+    InvalidLastSymbol,
+    /// Padding is missing or malformed
+    InvalidPadding,
+}
+
+/// Error of the fallible APIs of this crate
+///
+/// The errors of all individual APIs, such as `EnvError`, `DecodeError` or `CredentialError`,
+/// convert into `Error` through `From`, so a single type can be used with `?`. No variant can
+/// hold any part of a secret value: they consist of names, paths, offsets and lengths only.
+/// Conversions drop everything else, such as the scrubbed message of a `ParseError` or the
+/// payload of an `io::Error`.
+///
+/// Without the `std` feature, only the variants that do not need an allocation are available.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// Reading or writing a secret failed
+    #[cfg(feature = "std")]
+    Io {
+        /// Path of the file, if known
+        path: Option<PathBuf>,
+        /// Kind of the underlying error
+        kind: io::ErrorKind,
+    },
+    /// An environment variable is not set
+    #[cfg(feature = "std")]
+    MissingEnv {
+        /// Name of the variable
+        name: String,
+    },
+    /// An environment variable does not contain valid unicode
+    #[cfg(feature = "std")]
+    NotUnicodeEnv {
+        /// Name of the variable
+        name: String,
+    },
+    /// Decoding an encoded secret failed
+    Decode {
+        /// What went wrong
+        kind: DecodeKind,
+        /// Offset of the offending character or symbol, if known
+        offset: Option<usize>,
+    },
+    /// Parsing a secret failed
+    Parse {
+        /// Name of the type the secret was supposed to be parsed into
+        target: &'static str,
+    },
+    /// A secret does not have the expected length
+    Length {
+        /// The expected length
+        expected: usize,
+        /// The actual length
+        actual: usize,
+    },
+    /// A secret is not valid UTF-8
+    Utf8 {
+        /// Length of the valid UTF-8 prefix
+        valid_up_to: usize,
+    },
+    /// A secret contains a NUL byte
+    #[cfg(feature = "std")]
+    Nul {
+        /// Offset of the first NUL byte
+        offset: usize,
+    },
+    /// Loading a systemd credential failed
+    #[cfg(feature = "std")]
+    Credential {
+        /// Name of the credential
+        name: String,
+        /// Kind of the underlying error, `InvalidInput` for invalid names
+        kind: io::ErrorKind,
+    },
+    /// A `SecretProvider` failed to look up a secret
+    #[cfg(feature = "std")]
+    Provider {
+        /// Description of the provider
+        provider: String,
+        /// The key that was looked up
+        key: String,
+    },
+    /// The credential store of the operating system failed
+    #[cfg(all(feature = "keyring", feature = "std"))]
+    Keyring {
+        /// Whether the stored value is not valid UTF-8, rather than the store failing
+        bad_encoding: bool,
+    },
+    /// Decrypting an `EncryptedSecret` failed
+    #[cfg(feature = "encrypt")]
+    Decrypt,
+    /// Memory could not be locked against swapping
+    #[cfg(all(feature = "mlock", any(unix, windows)))]
+    Lock {
+        /// Kind of the error reported by the operating system
+        kind: io::ErrorKind,
+    },
+}
+
+#[cfg(feature = "std")]
+impl Error {
+    /// Creates an `Error::Io` for the file at `path`
+    #[inline]
+    pub fn io<F: Into<PathBuf>>(path: F, err: io::Error) -> Error {
+        Error::Io {
+            path: Some(path.into()),
+            kind: err.kind(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            #[cfg(feature = "std")]
+            Error::Io {
+                path: Some(path),
+                kind,
+            } => write!(
+                f,
+                "could not access secret file `{}`: {:?}",
+                path.display(),
+                kind
+            ),
+            #[cfg(feature = "std")]
+            Error::Io { path: None, kind } => write!(f, "could not read secret: {:?}", kind),
+            #[cfg(feature = "std")]
+            Error::MissingEnv { name } => write!(f, "environment variable `{}` is not set", name),
+            #[cfg(feature = "std")]
+            Error::NotUnicodeEnv { name } => write!(
+                f,
+                "environment variable `{}` does not contain valid unicode",
+                name
+            ),
+            Error::Decode { kind, offset } => {
+                f.write_str("could not decode secret: ")?;
+                f.write_str(match kind {
+                    DecodeKind::InvalidCharacter => "invalid character",
+                    DecodeKind::InvalidLength => "invalid length",
+                    DecodeKind::InvalidLastSymbol => "invalid last symbol",
+                    DecodeKind::InvalidPadding => "invalid padding",
+                })?;
+                match offset {
+                    Some(offset) => write!(f, " at offset {}", offset),
+                    None => Ok(()),
+                }
+            }
+            Error::Parse { target } => write!(
+                f,
+                "a confidential value could not be parsed as `{}`",
+                target
+            ),
+            Error::Length { expected, actual } => write!(
+                f,
+                "expected a secret of {} bytes, got {} bytes",
+                expected, actual
+            ),
+            Error::Utf8 { valid_up_to } => {
+                write!(f, "secret is not valid UTF-8 after {} bytes", valid_up_to)
+            }
+            #[cfg(feature = "std")]
+            Error::Nul { offset } => write!(f, "secret contains a NUL byte at offset {}", offset),
+            #[cfg(feature = "std")]
+            Error::Credential { name, kind } => {
+                write!(f, "could not load credential `{}`: {:?}", name, kind)
+            }
+            #[cfg(feature = "std")]
+            Error::Provider { provider, key } => {
+                write!(f, "could not get secret `{}` from {}", key, provider)
+            }
+            #[cfg(all(feature = "keyring", feature = "std"))]
+            Error::Keyring { bad_encoding: true } => {
+                f.write_str("stored secret is not valid UTF-8")
+            }
+            #[cfg(all(feature = "keyring", feature = "std"))]
+            Error::Keyring {
+                bad_encoding: false,
+            } => f.write_str("credential store failed"),
+            #[cfg(feature = "encrypt")]
+            Error::Decrypt => {
+                f.write_str("could not decrypt secret: wrong key or modified ciphertext")
+            }
+            #[cfg(all(feature = "mlock", any(unix, windows)))]
+            Error::Lock { kind } => write!(f, "could not lock memory: {:?}", kind),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// Keeps only the kind of the error, dropping its message, which may contain anything
+///
+/// The path is kept for errors of the file APIs of this crate, such as `Secret::from_file`.
+#[cfg(feature = "std")]
+impl From<io::Error> for Error {
+    #[inline]
+    fn from(err: io::Error) -> Error {
+        let path = err
+            .get_ref()
+            .and_then(|inner| inner.downcast_ref::<FileError>())
+            .map(|inner| inner.path.clone());
+        Error::Io {
+            path,
+            kind: err.kind(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<EnvError> for Error {
+    #[inline]
+    fn from(err: EnvError) -> Error {
+        match err {
+            EnvError::NotPresent { name } => Error::MissingEnv { name },
+            EnvError::NotUnicode { name } => Error::NotUnicodeEnv { name },
+        }
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "base64"))]
+impl From<DecodeError> for Error {
+    #[inline]
+    fn from(err: DecodeError) -> Error {
+        let (kind, offset) = match err {
+            DecodeError::InvalidByte { offset } => (DecodeKind::InvalidCharacter, Some(offset)),
+            DecodeError::InvalidLength => (DecodeKind::InvalidLength, None),
+            DecodeError::InvalidLastSymbol { offset } => {
+                (DecodeKind::InvalidLastSymbol, Some(offset))
+            }
+            DecodeError::InvalidPadding => (DecodeKind::InvalidPadding, None),
+        };
+        Error::Decode { kind, offset }
+    }
+}
+
+#[cfg(feature = "hex")]
+impl From<HexError> for Error {
+    #[inline]
+    fn from(err: HexError) -> Error {
+        let (kind, offset) = match err {
+            HexError::InvalidCharacter { offset } => (DecodeKind::InvalidCharacter, Some(offset)),
+            HexError::OddLength | HexError::InvalidLength => (DecodeKind::InvalidLength, None),
+        };
+        Error::Decode { kind, offset }
+    }
+}
+
+/// Keeps only the target type, dropping the scrubbed message of the underlying error
+impl From<ParseError> for Error {
+    #[inline]
+    fn from(err: ParseError) -> Error {
+        Error::Parse {
+            target: err.target(),
+        }
+    }
+}
+
+impl From<LengthError> for Error {
+    #[inline]
+    fn from(err: LengthError) -> Error {
+        Error::Length {
+            expected: err.expected(),
+            actual: err.actual(),
+        }
+    }
+}
+
+/// Keeps only the offset, dropping the bytes that failed to convert
+#[cfg(feature = "alloc")]
+impl<P> From<Utf8SecretError<P>> for Error {
+    #[inline]
+    fn from(err: Utf8SecretError<P>) -> Error {
+        Error::Utf8 {
+            valid_up_to: err.valid_up_to(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<NulSecretError> for Error {
+    #[inline]
+    fn from(err: NulSecretError) -> Error {
+        Error::Nul {
+            offset: err.nul_position(),
+        }
+    }
+}
+
+/// Keeps only the kind of the underlying error, dropping its message
+///
+/// A missing `CREDENTIALS_DIRECTORY` is reported as `Error::MissingEnv`.
+#[cfg(feature = "std")]
+impl From<CredentialError> for Error {
+    fn from(err: CredentialError) -> Error {
+        let (name, kind) = match err {
+            CredentialError::NoCredentialsDirectory => {
+                return Error::MissingEnv {
+                    name: "CREDENTIALS_DIRECTORY".to_string(),
+                }
+            }
+            CredentialError::InvalidName { name } => (name, io::ErrorKind::InvalidInput),
+            CredentialError::NotFound { name } => (name, io::ErrorKind::NotFound),
+            CredentialError::NotUnicode { name } => (name, io::ErrorKind::InvalidData),
+            CredentialError::Io { name, err } => (name, err.kind()),
+        };
+        Error::Credential { name, kind }
+    }
+}
+
+/// Keeps the provider and the key, dropping the underlying cause
+#[cfg(feature = "std")]
+impl From<ProviderError> for Error {
+    #[inline]
+    fn from(err: ProviderError) -> Error {
+        Error::Provider {
+            provider: err.provider().to_string(),
+            key: err.key().to_string(),
+        }
+    }
+}
+
+/// Drops the error of the credential store
+#[cfg(all(feature = "keyring", feature = "std"))]
+impl From<KeyringError> for Error {
+    #[inline]
+    fn from(err: KeyringError) -> Error {
+        Error::Keyring {
+            bad_encoding: matches!(err, KeyringError::BadEncoding),
+        }
+    }
+}
+
+#[cfg(feature = "encrypt")]
+impl From<OpenError> for Error {
+    #[inline]
+    fn from(_: OpenError) -> Error {
+        Error::Decrypt
+    }
+}
+
+/// Keeps only the kind of the error reported by the operating system
+#[cfg(all(feature = "mlock", any(unix, windows)))]
+impl From<LockError> for Error {
+    #[inline]
+    fn from(err: LockError) -> Error {
+        Error::Lock {
+            kind: err.os_error().kind(),
+        }
+    }
+}
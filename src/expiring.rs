@@ -0,0 +1,91 @@
+use core::fmt;
+use std::time::{Duration, Instant};
+
+use super::{audit, Secret};
+
+/// A secret that can no longer be revealed after a deadline
+///
+/// Intended for short-lived credentials like OAuth access tokens. All methods depending on the
+/// current time have an `_at` variant taking the time explicitly.
+pub struct ExpiringSecret<T> {
+    secret: Secret<T>,
+    expires_at: Instant,
+}
+
+impl<T> ExpiringSecret<T> {
+    /// Creates a new secret, expiring at `expires_at`
+    #[inline]
+    pub fn new(val: T, expires_at: Instant) -> ExpiringSecret<T> {
+        ExpiringSecret {
+            secret: Secret::wrap(val),
+            expires_at,
+        }
+    }
+
+    /// Creates a new secret, expiring `ttl` from now
+    #[inline]
+    pub fn with_ttl(val: T, ttl: Duration) -> ExpiringSecret<T> {
+        ExpiringSecret::new(val, Instant::now() + ttl)
+    }
+
+    /// Returns the deadline after which the secret can no longer be revealed
+    #[inline]
+    pub fn expires_at(&self) -> Instant {
+        self.expires_at
+    }
+
+    /// Returns whether the secret has expired
+    #[inline]
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(Instant::now())
+    }
+
+    /// Returns whether the secret has expired at `now`
+    #[inline]
+    pub fn is_expired_at(&self, now: Instant) -> bool {
+        now >= self.expires_at
+    }
+
+    /// **Reveals** the held value by returning a reference, unless it has expired
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "reveal only in approved modules, see `RevealToken`")
+    )]
+    #[allow(deprecated)]
+    pub fn reveal_if_valid(&self) -> Option<&T> {
+        self.reveal_if_valid_at(Instant::now())
+    }
+
+    /// **Reveals** the held value by returning a reference, unless it has expired at `now`
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "reveal only in approved modules, see `RevealToken`")
+    )]
+    pub fn reveal_if_valid_at(&self, now: Instant) -> Option<&T> {
+        if self.is_expired_at(now) {
+            return None;
+        }
+
+        audit::revealed::<T>();
+        Some(&self.secret.1)
+    }
+
+    /// Replaces the held value and deadline, e.g. after renewing a token
+    #[inline]
+    pub fn refresh(&mut self, val: T, expires_at: Instant) {
+        self.secret = Secret::wrap(val);
+        self.expires_at = expires_at;
+    }
+}
+
+impl<T> fmt::Debug for ExpiringSecret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ExpiringSecret")
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
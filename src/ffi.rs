@@ -0,0 +1,85 @@
+//! Passing secrets to C functions
+
+use std::error::Error;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::os::raw::c_char;
+use std::string::String;
+
+use super::{audit, Secret};
+
+/// Error returned when a secret cannot be converted into a `CString`
+///
+/// Unlike `NulError`, which contains the whole input, this error only exposes the position of the
+/// interior NUL byte.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NulSecretError {
+    position: usize,
+}
+
+impl NulSecretError {
+    /// Returns the offset of the first NUL byte in the secret
+    #[inline]
+    pub fn nul_position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for NulSecretError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "a confidential value contains a NUL byte at offset {}",
+            self.position
+        )
+    }
+}
+
+impl Error for NulSecretError {}
+
+impl<P> Secret<String, P> {
+    /// Copies the held string into a secret `CString`, for passing it to C functions
+    ///
+    /// Fails if the string contains a NUL byte.
+    ///
+    /// With the `zeroize` feature, `Secret<CString>` implements `Zeroize`. Like every other secret,
+    /// it is not wiped on drop by itself; wrap it in a `zeroize::Zeroizing` for that.
+    #[inline]
+    pub fn to_cstring_secret(&self) -> Result<Secret<CString, P>, NulSecretError> {
+        CString::new(self.1.as_bytes())
+            .map(Secret::wrap)
+            .map_err(|err| NulSecretError {
+                position: err.nul_position(),
+            })
+    }
+}
+
+impl<P> Secret<CString, P> {
+    /// Returns and **reveals** a `CStr` reference
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `reveal_with` and a `RevealToken`")
+    )]
+    pub fn reveal_c_str(&self) -> &CStr {
+        audit::revealed::<CString>();
+        self.1.as_c_str()
+    }
+
+    /// Returns and **reveals** a pointer to the held NUL-terminated string
+    ///
+    /// Meant as the very last step before calling a C function. The pointer is valid as long as
+    /// the secret is neither dropped nor modified; the C side must not write through it or keep it
+    /// beyond that.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `reveal_with` and a `RevealToken`")
+    )]
+    pub fn reveal_ptr(&self) -> *const c_char {
+        audit::revealed::<CString>();
+        self.1.as_ptr()
+    }
+}
@@ -0,0 +1,41 @@
+use alloc::string::String;
+use sha2::{Digest, Sha256};
+
+use super::Secret;
+
+/// Number of digest bytes included in a short fingerprint (12 hex characters)
+const SHORT_LEN: usize = 6;
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+fn to_hex(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        out.push(HEX_DIGITS[(b >> 4) as usize] as char);
+        out.push(HEX_DIGITS[(b & 0x0f) as usize] as char);
+    }
+    out
+}
+
+impl<T: AsRef<[u8]> + ?Sized, P> Secret<T, P> {
+    /// Returns a short, loggable fingerprint of the held value
+    ///
+    /// The fingerprint consists of the first 12 hex characters of the SHA-256 digest of the value
+    /// and can be used to tell whether two secrets are identical without revealing either.
+    ///
+    /// Fingerprints of low-entropy secrets such as passwords can be brute-forced easily and
+    /// **must not** be logged. Only use fingerprints for high-entropy values such as random tokens
+    /// or keys.
+    #[inline]
+    pub fn fingerprint(&self) -> String {
+        to_hex(&Sha256::digest(self.1.as_ref())[..SHORT_LEN])
+    }
+
+    /// Returns the full hex-encoded SHA-256 digest of the held value
+    ///
+    /// See `fingerprint` for caveats.
+    #[inline]
+    pub fn fingerprint_full(&self) -> String {
+        to_hex(&Sha256::digest(self.1.as_ref()))
+    }
+}
@@ -0,0 +1,56 @@
+//! Ordering by hash, enabled by the `hashed-ord` feature
+//!
+//! Unlike the `ord` feature, this ordering does not reveal which of two secrets is larger. Secrets
+//! are ordered by a stable hash of their value first, which gives an order that is deterministic
+//! but unrelated to the values. Only if two different values have the same hash, they are ordered
+//! by value, which keeps `Ord` consistent with `Eq`.
+
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+use super::Secret;
+
+/// 64-bit FNV-1a, with a SplitMix64 finalizer to spread the bits of short inputs
+struct OrderHasher(u64);
+
+impl Hasher for OrderHasher {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+/// Returns the hash that secrets holding `val` are ordered by
+#[inline]
+fn order_hash<T: Hash + ?Sized>(val: &T) -> u64 {
+    let mut hasher = OrderHasher(0xcbf2_9ce4_8422_2325);
+    val.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl<T: Hash + Ord + ?Sized, P> PartialOrd for Secret<T, P> {
+    #[inline]
+    fn partial_cmp(&self, other: &Secret<T, P>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Hash + Ord + ?Sized, P> Ord for Secret<T, P> {
+    #[inline]
+    fn cmp(&self, other: &Secret<T, P>) -> Ordering {
+        order_hash(&self.1)
+            .cmp(&order_hash(&other.1))
+            .then_with(|| self.1.cmp(&other.1))
+    }
+}
@@ -0,0 +1,26 @@
+use secrecy::zeroize::Zeroize;
+use secrecy::ExposeSecret;
+
+use super::Secret;
+
+/// Converts from a `secrecy::Secret`, e.g. a `secrecy::SecretString` or `secrecy::SecretVec<u8>`
+///
+/// Since a `secrecy::Secret` does not allow moving its value out, it is cloned. The original is
+/// zeroized when dropped at the end of the conversion.
+impl<T: Zeroize + Clone> From<secrecy::Secret<T>> for Secret<T> {
+    #[inline]
+    fn from(secret: secrecy::Secret<T>) -> Secret<T> {
+        Secret::wrap(secret.expose_secret().clone())
+    }
+}
+
+impl<T: Zeroize, P> Secret<T, P> {
+    /// Converts into a `secrecy::Secret`, without copying the held value
+    ///
+    /// Allows passing a `Secret<String>` to a library expecting a `secrecy::SecretString`, or a
+    /// `Secret<Vec<u8>>` to one expecting a `secrecy::SecretVec<u8>`.
+    #[inline]
+    pub fn into_secrecy(self) -> secrecy::Secret<T> {
+        secrecy::Secret::new(self.1)
+    }
+}
@@ -0,0 +1,59 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use super::{Ellipsis, Secret};
+
+/// An iterator wrapping every item of an inner iterator in a `Secret`
+///
+/// Returned by `Secret::iter` and `Secret::into_iter_secret`.
+pub struct SecretIter<I, P = Ellipsis>(I, PhantomData<P>);
+
+impl<I: Iterator, P> Iterator for SecretIter<I, P> {
+    type Item = Secret<I::Item, P>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(Secret::wrap)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator, P> DoubleEndedIterator for SecretIter<I, P> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(Secret::wrap)
+    }
+}
+
+impl<I: ExactSizeIterator, P> ExactSizeIterator for SecretIter<I, P> {}
+
+impl<I, P> fmt::Debug for SecretIter<I, P> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SecretIter(...)")
+    }
+}
+
+impl<C, P> Secret<C, P> {
+    /// Iterates over the held collection by reference, wrapping every item in a secret
+    #[inline]
+    pub fn iter<'a>(&'a self) -> SecretIter<<&'a C as IntoIterator>::IntoIter, P>
+    where
+        &'a C: IntoIterator,
+    {
+        SecretIter(self.1.into_iter(), PhantomData)
+    }
+
+    /// Iterates over the held collection by value, wrapping every item in a secret
+    #[inline]
+    pub fn into_iter_secret(self) -> SecretIter<C::IntoIter, P>
+    where
+        C: IntoIterator,
+    {
+        SecretIter(self.1.into_iter(), PhantomData)
+    }
+}
@@ -0,0 +1,113 @@
+//! Keyed hashing of secrets
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+use core::hash::{Hash, Hasher};
+
+use super::Secret;
+
+/// SipHash-2-4, as described in "SipHash: a fast short-input PRF" by Aumasson and Bernstein
+pub(crate) struct SipHasher24 {
+    v: [u64; 4],
+    tail: u64,
+    tail_len: usize,
+    len: usize,
+}
+
+impl SipHasher24 {
+    /// Creates a hasher keyed with `key`
+    pub(crate) fn new(key: &[u8; 16]) -> SipHasher24 {
+        let mut k0 = [0; 8];
+        let mut k1 = [0; 8];
+        k0.copy_from_slice(&key[..8]);
+        k1.copy_from_slice(&key[8..]);
+        let (k0, k1) = (u64::from_le_bytes(k0), u64::from_le_bytes(k1));
+
+        SipHasher24 {
+            v: [
+                k0 ^ 0x736f_6d65_7073_6575,
+                k1 ^ 0x646f_7261_6e64_6f6d,
+                k0 ^ 0x6c79_6765_6e65_7261,
+                k1 ^ 0x7465_6462_7974_6573,
+            ],
+            tail: 0,
+            tail_len: 0,
+            len: 0,
+        }
+    }
+
+    #[inline]
+    fn round(v: &mut [u64; 4]) {
+        v[0] = v[0].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(13) ^ v[0];
+        v[0] = v[0].rotate_left(32);
+        v[2] = v[2].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(16) ^ v[2];
+        v[0] = v[0].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(21) ^ v[0];
+        v[2] = v[2].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(17) ^ v[2];
+        v[2] = v[2].rotate_left(32);
+    }
+
+    #[inline]
+    fn compress(v: &mut [u64; 4], m: u64) {
+        v[3] ^= m;
+        SipHasher24::round(v);
+        SipHasher24::round(v);
+        v[0] ^= m;
+    }
+}
+
+impl Hasher for SipHasher24 {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.tail |= u64::from(byte) << (8 * self.tail_len);
+            self.tail_len += 1;
+            if self.tail_len == 8 {
+                SipHasher24::compress(&mut self.v, self.tail);
+                self.tail = 0;
+                self.tail_len = 0;
+            }
+        }
+        self.len = self.len.wrapping_add(bytes.len());
+    }
+
+    fn finish(&self) -> u64 {
+        let mut v = self.v;
+        SipHasher24::compress(&mut v, ((self.len as u64) << 56) | self.tail);
+        v[2] ^= 0xff;
+        for _ in 0..4 {
+            SipHasher24::round(&mut v);
+        }
+        v[0] ^ v[1] ^ v[2] ^ v[3]
+    }
+}
+
+impl<T: Hash + ?Sized, P> Secret<T, P> {
+    /// Hashes the held value with SipHash-2-4, keyed with `key`
+    ///
+    /// Unlike `fingerprint`, which anyone can compute for a guessed value, a keyed hash can only be
+    /// computed and compared by those knowing the key. Hashes of the same value with different keys
+    /// are unrelated, so keys can be rotated or chosen per context to prevent correlating secrets
+    /// across systems.
+    ///
+    /// The value is fed to the hasher through its `Hash` implementation, so results are stable for
+    /// the same type and platform, but may differ between platforms, e.g. for `usize`. A 64-bit
+    /// hash is not collision-resistant and is meant as an identifier, not as a MAC.
+    #[inline]
+    pub fn hash_with_key(&self, key: &[u8; 16]) -> u64 {
+        let mut hasher = SipHasher24::new(key);
+        self.1.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Returns `hash_with_key` as 16 hex characters, usable as a pseudonymous identifier
+    ///
+    /// See `hash_with_key` for caveats.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn hashed_id(&self, key: &[u8; 16]) -> String {
+        format!("{:016x}", self.hash_with_key(key))
+    }
+}
@@ -0,0 +1,86 @@
+//! Storing secrets in the credential store of the operating system
+//!
+//! Wraps the [keyring](https://crates.io/crates/keyring) crate, which uses the macOS Keychain, the
+//! Windows Credential Manager or the Secret Service on Linux. Secrets only leave a `Secret` to be
+//! handed to the store, and errors never contain any stored value.
+//!
+//! ```ignore
+//! sec::keyring::store("my-cli", "alice", &token)?;
+//! let token = sec::keyring::load("my-cli", "alice")?;
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::string::String;
+
+use ::keyring::Entry;
+
+use super::Secret;
+
+/// Error returned by the credential store
+///
+/// Errors of the `keyring` crate that could contain a stored value are replaced.
+#[derive(Debug)]
+pub enum KeyringError {
+    /// The stored value is not valid UTF-8
+    BadEncoding,
+    /// Any other error reported by the credential store
+    Store(::keyring::Error),
+}
+
+impl KeyringError {
+    fn new(err: ::keyring::Error) -> KeyringError {
+        match err {
+            // contains the stored value
+            ::keyring::Error::BadEncoding(_) => KeyringError::BadEncoding,
+            err => KeyringError::Store(err),
+        }
+    }
+}
+
+impl fmt::Display for KeyringError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KeyringError::BadEncoding => f.write_str("stored secret is not valid UTF-8"),
+            KeyringError::Store(err) => write!(f, "credential store failed: {}", err),
+        }
+    }
+}
+
+impl Error for KeyringError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            KeyringError::BadEncoding => None,
+            KeyringError::Store(err) => Some(err),
+        }
+    }
+}
+
+/// Stores a secret for `account` of `service`, replacing any previously stored value
+pub fn store<P>(
+    service: &str,
+    account: &str,
+    secret: &Secret<String, P>,
+) -> Result<(), KeyringError> {
+    Entry::new(service, account)
+        .and_then(|entry| entry.set_password(&secret.1))
+        .map_err(KeyringError::new)
+}
+
+/// Loads the secret of `account` of `service`, returning `None` if there is none
+pub fn load(service: &str, account: &str) -> Result<Option<Secret<String>>, KeyringError> {
+    match Entry::new(service, account).and_then(|entry| entry.get_password()) {
+        Ok(password) => Ok(Some(Secret::wrap(password))),
+        Err(::keyring::Error::NoEntry) => Ok(None),
+        Err(err) => Err(KeyringError::new(err)),
+    }
+}
+
+/// Deletes the secret of `account` of `service`, returning whether there was one
+pub fn delete(service: &str, account: &str) -> Result<bool, KeyringError> {
+    match Entry::new(service, account).and_then(|entry| entry.delete_password()) {
+        Ok(()) => Ok(true),
+        Err(::keyring::Error::NoEntry) => Ok(false),
+        Err(err) => Err(KeyringError::new(err)),
+    }
+}
@@ -0,0 +1,164 @@
+//! Conversions relying on the layout of `Secret<T>` being identical to `T`
+//!
+//! All `unsafe` code depending on `#[repr(transparent)]` is kept in this module.
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::borrow::Borrow;
+#[cfg(feature = "alloc")]
+use core::mem::ManuallyDrop;
+use core::slice;
+
+use super::Secret;
+
+impl<'a, T, P> Secret<&'a [T], P> {
+    /// Turns a slice of secrets into a secret slice, without copying
+    #[inline]
+    pub fn as_secret_slice(secrets: &'a [Secret<T, P>]) -> Secret<&'a [T], P> {
+        // SAFETY: `Secret<T>` is `repr(transparent)`, so `[Secret<T>]` and `[T]` have the same
+        // layout. The lifetime of the resulting slice is bound to the input.
+        Secret::wrap(unsafe { slice::from_raw_parts(secrets.as_ptr() as *const T, secrets.len()) })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, P> Secret<Vec<T>, P> {
+    /// Turns a `Vec` of secrets into a secret `Vec`, without reallocating
+    #[inline]
+    pub fn from_vec(secrets: Vec<Secret<T, P>>) -> Secret<Vec<T>, P> {
+        let mut secrets = ManuallyDrop::new(secrets);
+        let (ptr, len, cap) = (secrets.as_mut_ptr(), secrets.len(), secrets.capacity());
+
+        // SAFETY: `Secret<T>` is `repr(transparent)`, so the allocation has the correct size and
+        // alignment for `T`. Ownership of the allocation is transferred, the original `Vec` is
+        // never dropped.
+        Secret::wrap(unsafe { Vec::from_raw_parts(ptr as *mut T, len, cap) })
+    }
+
+    /// Turns a secret `Vec` into a `Vec` of secrets, without reallocating
+    #[inline]
+    pub fn into_secrets(self) -> Vec<Secret<T, P>> {
+        let mut values = ManuallyDrop::new(self.1);
+        let (ptr, len, cap) = (values.as_mut_ptr(), values.len(), values.capacity());
+
+        // SAFETY: See `from_vec`.
+        unsafe { Vec::from_raw_parts(ptr as *mut Secret<T, P>, len, cap) }
+    }
+}
+
+/// Turns a reference to a possibly unsized value into a reference to a secret
+#[inline]
+fn wrap_ref<T: ?Sized, P>(val: &T) -> &Secret<T, P> {
+    // SAFETY: `Secret<T, P>` is `repr(transparent)` over `T`, the only other field being a
+    // `PhantomData`, so both have the same layout and pointer metadata.
+    unsafe { &*(val as *const T as *const Secret<T, P>) }
+}
+
+impl Secret<str> {
+    /// Borrows a string as a secret `str`, without copying
+    ///
+    /// Allows looking up entries of a map keyed by `Secret<String>` without allocating a key:
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use sec::Secret;
+    ///
+    /// let mut sessions = HashMap::new();
+    /// sessions.insert(Secret::new("THIS-SHOULD-BE-SECRET".to_owned()), "alice");
+    ///
+    /// let token: &str = "THIS-SHOULD-BE-SECRET";
+    /// assert_eq!(sessions.get(Secret::from_str_ref(token)), Some(&"alice"));
+    /// ```
+    #[inline]
+    pub fn from_str_ref(s: &str) -> &Secret<str> {
+        wrap_ref(s)
+    }
+}
+
+impl Secret<[u8]> {
+    /// Borrows a byte slice as a secret byte slice, without copying
+    ///
+    /// See `Secret::from_str_ref`.
+    #[inline]
+    pub fn from_bytes_ref(bytes: &[u8]) -> &Secret<[u8]> {
+        wrap_ref(bytes)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> Secret<String, P> {
+    /// Borrows the held string as a secret `str`, without copying
+    #[inline]
+    pub fn as_secret_str(&self) -> &Secret<str, P> {
+        wrap_ref(self.1.as_str())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> Secret<Vec<u8>, P> {
+    /// Borrows the held bytes as a secret byte slice, without copying
+    #[inline]
+    pub fn as_secret_bytes(&self) -> &Secret<[u8], P> {
+        wrap_ref(self.1.as_slice())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> Borrow<Secret<str, P>> for Secret<String, P> {
+    #[inline]
+    fn borrow(&self) -> &Secret<str, P> {
+        self.as_secret_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> Borrow<Secret<[u8], P>> for Secret<Vec<u8>, P> {
+    #[inline]
+    fn borrow(&self) -> &Secret<[u8], P> {
+        self.as_secret_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized, P> Secret<Box<T>, P> {
+    /// Turns a boxed secret into a secret box, without reallocating
+    #[inline]
+    pub fn from_box(secret: Box<Secret<T, P>>) -> Secret<Box<T>, P> {
+        // SAFETY: See `wrap_ref`. Ownership of the allocation is transferred.
+        Secret::wrap(unsafe { Box::from_raw(Box::into_raw(secret) as *mut T) })
+    }
+
+    /// Turns a secret box into a boxed secret, without reallocating
+    #[inline]
+    pub fn into_box_secret(self) -> Box<Secret<T, P>> {
+        // SAFETY: See `wrap_ref`. Ownership of the allocation is transferred.
+        unsafe { Box::from_raw(Box::into_raw(self.1) as *mut Secret<T, P>) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized, P> Secret<Arc<T>, P> {
+    /// Turns a shared secret into a secret `Arc`, without reallocating
+    #[inline]
+    pub fn from_arc(secret: Arc<Secret<T, P>>) -> Secret<Arc<T>, P> {
+        // SAFETY: See `wrap_ref`. The reference count is carried over, since the `Arc` is turned
+        // into a raw pointer and back exactly once.
+        Secret::wrap(unsafe { Arc::from_raw(Arc::into_raw(secret) as *const T) })
+    }
+
+    /// Turns a secret `Arc` into a shared secret, without reallocating
+    #[inline]
+    pub fn into_arc_secret(self) -> Arc<Secret<T, P>> {
+        // SAFETY: See `from_arc`.
+        unsafe { Arc::from_raw(Arc::into_raw(self.1) as *const Secret<T, P>) }
+    }
+
+    /// Creates a secret reference to the shared value
+    ///
+    /// This is the same as `as_deref`.
+    #[inline]
+    pub fn as_ref_secret(&self) -> Secret<&T, P> {
+        Secret::wrap(&*self.1)
+    }
+}
@@ -0,0 +1,88 @@
+use core::fmt;
+use std::sync::OnceLock;
+
+use super::{audit, Secret};
+
+/// A secret that is initialized on first use
+///
+/// Meant for statics holding global configuration, e.g. a token loaded from the environment:
+///
+/// ```rust
+/// use sec::LazySecret;
+///
+/// static TOKEN: LazySecret<String> = LazySecret::new(|| "THIS-SHOULD-BE-SECRET".to_owned());
+///
+/// assert_eq!(TOKEN.reveal(), "THIS-SHOULD-BE-SECRET");
+/// ```
+///
+/// Initialization is thread-safe: if several threads access an uninitialized `LazySecret` at the
+/// same time, the initializer runs once and all of them see its result.
+pub struct LazySecret<T, F = fn() -> T> {
+    cell: OnceLock<T>,
+    init: F,
+}
+
+impl<T, F> LazySecret<T, F> {
+    /// Creates a new lazily-initialized secret
+    #[inline]
+    pub const fn new(init: F) -> LazySecret<T, F> {
+        LazySecret {
+            cell: OnceLock::new(),
+            init,
+        }
+    }
+
+    /// Returns whether the secret has been initialized
+    #[inline]
+    pub fn is_initialized(&self) -> bool {
+        self.cell.get().is_some()
+    }
+
+    /// Initializes the secret with a fallible function, if it is not initialized yet
+    ///
+    /// Returns the held value if the secret is already initialized, or if `f` succeeds. If `f`
+    /// fails, the secret stays uninitialized and the error is returned. The stored initializer is
+    /// not used.
+    ///
+    /// Unlike the regular initialization, concurrent callers may each run their `f`; only the first
+    /// value to be stored is kept, all others are dropped.
+    pub fn get_or_try_init<E, G>(&self, f: G) -> Result<Secret<&T>, E>
+    where
+        G: FnOnce() -> Result<T, E>,
+    {
+        if let Some(val) = self.cell.get() {
+            return Ok(Secret::wrap(val));
+        }
+
+        let val = f()?;
+        Ok(Secret::wrap(self.cell.get_or_init(|| val)))
+    }
+}
+
+impl<T, F: Fn() -> T> LazySecret<T, F> {
+    /// Returns a reference to the held value in a `Secret`, initializing it if necessary
+    #[inline]
+    pub fn get(&self) -> Secret<&T> {
+        Secret::wrap(self.cell.get_or_init(&self.init))
+    }
+
+    /// **Reveals** the held value, initializing it if necessary
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `get` and `reveal_into_with`")
+    )]
+    pub fn reveal(&self) -> &T {
+        audit::revealed::<T>();
+        self.cell.get_or_init(&self.init)
+    }
+}
+
+impl<T, F> fmt::Debug for LazySecret<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LazySecret")
+            .field("initialized", &self.is_initialized())
+            .finish()
+    }
+}
@@ -55,6 +55,75 @@
 //! ```
 //!
 //! Only methods that contain `reveal` in their name actually allow accessing the secret value.
+//! The only deliberate exceptions are metadata accessors such as `len` and `is_empty`.
+//!
+//!
+//! ## Typed `Debug` output (`typed-debug` feature)
+//!
+//! By default, the `Debug` output of a secret is `...`, which is indistinguishable from a string
+//! that is literally `"..."`. With the `typed-debug` feature, the type of the held value is shown
+//! instead, e.g. `Secret<String>(REDACTED)`:
+//!
+//! ```ignore
+//! #[derive(Debug)]
+//! struct Config {
+//!     api_key: Secret<String>,
+//! }
+//!
+//! // prints `Config { api_key: Secret<String>(REDACTED) }`
+//! println!("{:?}", config);
+//! ```
+//!
+//! Only the type name is printed, never any part of the value.
+//!
+//!
+//! ## Revealing `Debug` output (`danger-reveal-debug` feature)
+//!
+//! **DANGER**: With the `danger-reveal-debug` feature, the `Debug` output of a secret contains the
+//! held value, e.g. `Secret("hunter2")`, and `Debug` is only implemented for `Secret<T>` if `T`
+//! implements `Debug`. This is meant solely for tracking down problems during local development,
+//! e.g. with deserialization. Never enable it in a library or commit it to a manifest; prefer
+//! `cargo test --features danger-reveal-debug` for a single run.
+//!
+//! No other feature enables it, and compilation fails if it is enabled for a build without debug
+//! assertions, such as the default `release` profile.
+//!
+//!
+//! ## Dereferencing secrets (`danger-deref` feature)
+//!
+//! **DANGER**: With the `danger-deref` feature, `Secret<T>` implements `Deref<Target = T>`, so
+//! that code written for plain values keeps compiling while fields are changed to secrets one by
+//! one. It is a temporary migration aid only: every method of `T` becomes callable on the secret
+//! without a `reveal`, including ones that format the value, such as `to_string` for any `T`
+//! implementing `Display`. `format!("{}", *secret)` prints the value as well. Each dereference is
+//! reported to the `audit` hook, which helps finding the remaining call sites.
+//!
+//! No other feature enables it. Without it, secrets cannot be dereferenced:
+//!
+//! ```compile_fail
+//! let password = sec::Secret::new(String::from("hunter2"));
+//! let _ = password.trim();
+//! ```
+//!
+//!
+//! ## Custom placeholders
+//!
+//! The text printed in place of the value can be changed by implementing `Placeholder` and passing
+//! it as the second type parameter of `Secret`. This is useful if logs are scanned for a specific
+//! marker:
+//!
+//! ```ignore
+//! struct Filtered;
+//!
+//! impl Placeholder for Filtered {
+//!     const PLACEHOLDER: &'static str = "[FILTERED]";
+//! }
+//!
+//! let api_key: Secret<String, Filtered> = Secret::with_placeholder(key);
+//!
+//! // prints `[FILTERED]`
+//! println!("{:?}", api_key);
+//! ```
 //!
 //!
 //! ## Serde support (`deserialize`/`serialize` features)
@@ -97,11 +166,343 @@
 //! Note that the only supported rocket version is a pinned dev version of rocket 0.5.
 //!
 //!
+//! ## Random secrets (`rand` feature)
+//!
+//! The `rand` feature adds constructors that fill a secret with random data obtained from the
+//! operating system, without the value ever existing outside of a `Secret`:
+//!
+//! ```ignore
+//! let key: Secret<[u8; 32]> = Secret::random();
+//! let nonce: Secret<Vec<u8>> = Secret::random_bytes(12);
+//! let session_token: Secret<String> = Secret::random_alphanumeric(32);
+//! ```
+//!
+//! Randomness is provided by [getrandom](https://crates.io/crates/getrandom), which works on
+//! `no_std` targets as well.
+//!
+//! `token::mint` creates API tokens with a public prefix and a checksum, such as
+//! `acme_4Jq0sVx7...Zp2kQ1`, which `token::validate_format` recognizes without any stored secret.
+//!
+//!
+//! ## Encodings (`base64` and `hex` features)
+//!
+//! With the `base64` and `hex` features, secrets can be encoded and decoded without the decoded
+//! bytes ever leaving a `Secret`:
+//!
+//! ```ignore
+//! let key: Secret<Vec<u8>> = Secret::new(env_var).decode_base64()?;
+//! let encoded: Secret<String> = key.to_base64_url();
+//!
+//! let hmac_key: Secret<[u8; 32]> = Secret::from_hex(config_value)?;
+//! ```
+//!
+//! Neither `DecodeError` nor `HexError` contain any part of the input. Except for decoding into
+//! fixed-size arrays, the `alloc` feature is required.
+//!
+//!
+//! ## Fingerprints (`sha2` feature)
+//!
+//! To check whether two environments use the same key without printing it, the `sha2` feature
+//! adds `fingerprint`, which returns the first 12 hex characters of the SHA-256 digest of a secret:
+//!
+//! ```ignore
+//! let key = Secret::new(String::from("THIS-SHOULD-BE-SECRET"));
+//! info!("using api key {}", key.fingerprint());
+//! ```
+//!
+//! Fingerprints of low-entropy secrets such as passwords can be brute-forced and should not be
+//! logged. The `alloc` feature is required.
+//!
+//!
+//! ## Key derivation (`hkdf` feature)
+//!
+//! `derive_key` derives independent keys for different purposes from a single master key with
+//! HKDF-SHA256, without revealing either:
+//!
+//! ```ignore
+//! let master: Secret<[u8; 32]> = load_master_key();
+//! let cookie_key: Secret<[u8; 32]> = master.derive_key(b"", b"cookie-signing");
+//! ```
+//!
+//!
+//! ## Auditing (`audit` feature)
+//!
+//! To find out where secrets are revealed at runtime, the `audit` feature allows installing a
+//! process-global hook that is called with the call site and type name on every reveal:
+//!
+//! ```ignore
+//! sec::set_reveal_hook(|location, type_name| {
+//!     log::info!("revealed a {} at {}", type_name, location);
+//! });
+//! ```
+//!
+//! The hook never receives the value itself. All functions that hand out the held value, or parts
+//! of it, are reported; functions like `map_revealed` that wrap their result in a `Secret` are not.
+//! Without a hook installed, the overhead is a single relaxed atomic load. The only unreported
+//! accessor is `reveal_const`, for `const` contexts.
+//!
+//!
+//! ## Restricting reveals (`capability` feature)
+//!
+//! To control at compile time which modules may reveal secrets, the `capability` feature
+//! deprecates the `reveal*` methods of `Secret`, and adds `reveal_with`, `reveal_mut_with` and
+//! `reveal_into_with`, which require a `RevealToken`:
+//!
+//! ```ignore
+//! // in the module approved to reveal secrets
+//! let token = sec::issue_reveal_token!();
+//! db.connect(config.password.reveal_with(&token));
+//! ```
+//!
+//! Building with `-D deprecated` then rejects any other reveal. Tokens can only be issued with
+//! the `issue_reveal_token!` macro, so all approved modules can be found with `grep`. The reveal
+//! accessors of the other types of this crate, such as `SecretBox` or `SecretOnce`, are
+//! deprecated as well; where they have no token-taking equivalent, approved modules allow them
+//! with `#[allow(deprecated)]`.
+//!
+//!
+//! ## Statistics (`stats` feature)
+//!
+//! For hunting leaks, the `stats` feature counts how many secrets are created and revealed, both
+//! in total and per type of the held value:
+//!
+//! ```ignore
+//! let snapshot = sec::stats::snapshot();
+//! log::debug!("{} reveals of secret strings", snapshot.of::<String>().revealed);
+//! ```
+//!
+//! See the `stats` module for what is counted. The number of secrets currently alive is not
+//! available, as `Secret` cannot count its drops.
+//!
+//!
+//! ## `secrecy` interoperability (`secrecy` feature)
+//!
+//! The `secrecy` feature adds conversions from and to the secrets of the
+//! [secrecy](https://crates.io/crates/secrecy) crate, without exposing the value in application
+//! code:
+//!
+//! ```ignore
+//! let key: Secret<String> = library_returning_secrecy_string().into();
+//! library_expecting_secrecy_string(key.into_secrecy());
+//! ```
+//!
+//!
+//! ## Loading secrets (`std` feature)
+//!
+//! `Secret::from_env` (or the `secret_env!` macro) reads a secret from an environment variable,
+//! and `Secret::from_file` from a file such as a Docker or Kubernetes secret mount, both removing
+//! a trailing newline. Errors only contain the name of the variable or the path of the file:
+//!
+//! ```ignore
+//! let api_key = secret_env!("API_KEY")?;
+//! let db_password = Secret::from_file("/run/secrets/db_password")?;
+//! ```
+//!
+//! Services run by systemd load credentials passed with `LoadCredential=` through
+//! `sec::systemd::credential`.
+//!
+//! The `provider` module abstracts over such sources with the `SecretProvider` trait, and
+//! `ChainProvider` tries several of them in order.
+//!
+//! With the `watch` feature, `WatchedSecret` reloads a secret from its file whenever it changes,
+//! e.g. when Kubernetes rotates a mounted secret.
+//!
+//! On Unix, `write_to_file_secure` atomically writes a secret to a file only readable by the
+//! current user, e.g. for caching a refresh token.
+//!
+//! `SecretCommandExt` passes secrets to child processes without a `reveal` at the call site:
+//!
+//! ```ignore
+//! use sec::SecretCommandExt;
+//!
+//! Command::new("psql").secret_env("PGPASSWORD", &db_password).status()?;
+//! ```
+//!
+//!
+//! ## Credential stores (`keyring` feature)
+//!
+//! The `keyring` module stores and loads secret strings in the credential store of the operating
+//! system, instead of in configuration files:
+//!
+//! ```ignore
+//! sec::keyring::store("my-cli", "alice", &token)?;
+//! let token: Option<Secret<String>> = sec::keyring::load("my-cli", "alice")?;
+//! ```
+//!
+//!
+//! ## Terminal prompts (`prompt` feature)
+//!
+//! `prompt` asks for a secret on the terminal without echoing it, and `prompt_confirm` asks twice
+//! and checks that both inputs match. The input never exists outside of a `Secret`:
+//!
+//! ```ignore
+//! let password = sec::prompt_confirm("New password: ", "Repeat password: ")?;
+//! ```
+//!
+//!
+//! ## Redacted errors (`std` feature)
+//!
+//! Errors often quote the input that caused them, so returning a parse error for a secret up the
+//! stack leaks it into logs. `SecretError` wraps such an error and only shows its type:
+//!
+//! ```ignore
+//! use sec::RedactErr;
+//!
+//! let key: Vec<u8> = serde_json::from_str(&raw).redact_err()?;
+//! ```
+//!
+//! The errors of the fallible APIs of this crate, such as `EnvError` or `DecodeError`, all convert
+//! into `sec::Error`, which holds names, paths, offsets and lengths only:
+//!
+//! ```ignore
+//! fn load_key() -> Result<Secret<Vec<u8>>, sec::Error> {
+//!     Ok(secret_env!("API_KEY")?.decode_base64()?)
+//! }
+//! ```
+//!
+//!
+//! ## `anyhow` support (`anyhow` feature)
+//!
+//! `SecContextExt::context_redacted` attaches context to an error like `anyhow::Context::context`,
+//! but replaces the error and everything below it with redacted stand-ins, so neither `{:#}` nor
+//! `{:?}` of the resulting `anyhow::Error` can show a connection string or token:
+//!
+//! ```ignore
+//! use sec::SecContextExt;
+//!
+//! let conn = connect(dsn.reveal()).context_redacted("could not connect to the database")?;
+//! ```
+//!
+//! For `thiserror` enums, `Redacted<E>` can be used as a `#[source]` or `#[from]` field.
+//!
+//!
+//! ## Comparing with plain values (`cmp-plain` feature)
+//!
+//! By default, a secret can only be compared with another secret, so checking a value requires an
+//! extra reveal, as in `*token.reveal() == "expected"`. The `cmp-plain` feature allows comparing a
+//! secret directly with a plain value of the held type, or a `Secret<String>` with a `&str`:
+//!
+//! ```ignore
+//! if token == "expected" {
+//!     // ...
+//! }
+//! ```
+//!
+//! This is off by default because such comparisons look like comparisons of plain values, which
+//! makes it easy to forget which side is secret. None of them are constant-time; see `verify`.
+//!
+//!
+//! ## Arithmetic (`ops` feature)
+//!
+//! The `ops` feature implements the arithmetic and bitwise operators for secrets holding values
+//! that support them. The result is a secret again, so `Secret<u64> + Secret<u64>` is a
+//! `Secret<u64>`. The primitive integers can also be combined with plain values:
+//!
+//! ```ignore
+//! let mut balance: Secret<u64> = load_balance()?;
+//! balance += deposit;
+//! let masked = balance ^ 0x5a5a_5a5a;
+//! ```
+//!
+//! `Sum` and `Product` are implemented as well, so an iterator of secrets can be summed into a
+//! secret.
+//!
+//!
+//! ## Test assertions (`std` feature)
+//!
+//! `assert_eq!(token.reveal(), "expected")` prints both values when it fails, which puts the secret
+//! into CI logs. `assert_secret_eq!` and `assert_secret_ne!` compare secrets and plain values
+//! without ever printing them; a failure only shows the types of both operands:
+//!
+//! ```ignore
+//! assert_secret_eq!(token, "THIS-SHOULD-BE-SECRET");
+//! ```
+//!
+//!
+//! ## Assembling secrets (`std` feature)
+//!
+//! Composite secrets such as connection strings contain both plain and secret parts. Instead of
+//! revealing every part into a temporary `String`, `secret_format!` accepts secrets as arguments
+//! and returns a `Secret<String>`:
+//!
+//! ```ignore
+//! let dsn = secret_format!("postgres://{}:{}@{}/{}", user, password, host, db);
+//! ```
+//!
+//! For fragments assembled in a loop, `SecretBuilder` does the same with `push_str` and
+//! `push_secret`.
+//!
+//!
+//! ## Obfuscated literals (`obfuscate` feature)
+//!
+//! Secrets created from string literals, such as development credentials, appear verbatim in the
+//! compiled binary. The `secret!` and `secret_bytes!` macros XOR-encode the literal at compile time
+//! with a key generated for every build and decode it at runtime:
+//!
+//! ```ignore
+//! let password: Secret<String> = secret!("THIS-SHOULD-BE-SECRET");
+//! let key: Secret<&[u8]> = secret_bytes!(b"THIS-SHOULD-BE-SECRET");
+//! ```
+//!
+//! This is obfuscation, not encryption; the key is stored in the same binary.
+//!
+//!
+//! ## Connection URLs (`url` feature)
+//!
+//! With the `url` feature, `Secret<url::Url>` exposes the scheme, host and port of a URL, and
+//! `redacted_display` renders it with the password replaced by `***`:
+//!
+//! ```ignore
+//! let dsn = Secret::<Url>::parse(&raw_dsn)?;
+//! log::info!("connecting to {}", dsn.redacted_display());
+//! ```
+//!
+//! `Secret::from_url_split` moves the password out of a URL into a separate secret instead.
+//!
+//!
+//! ## Scrubbing output (`std` feature)
+//!
+//! Values handed to other libraries may still end up in their output. `ScrubWriter` wraps an
+//! `io::Write` and replaces the secrets it was given with `[REDACTED]`. Secrets registered with
+//! `registry::register` are removed from any string by `registry::scrub`, and from panic messages
+//! once `install_scrubbing_panic_hook` has been called:
+//!
+//! ```ignore
+//! sec::install_scrubbing_panic_hook();
+//! sec::registry::register(&api_token)?;
+//! ```
+//!
+//!
+//! ## Encryption in memory (`encrypt` feature)
+//!
+//! `EncryptedSecret` keeps a secret string or byte buffer encrypted with ChaCha20-Poly1305 under a
+//! random key of the process, and only decrypts it on access:
+//!
+//! ```ignore
+//! let sealed = EncryptedSecret::seal(token, ProcessKey::global());
+//! let token = sealed.open(ProcessKey::global())?;
+//! ```
+//!
+//!
+//! ## Locked memory (`mlock` feature)
+//!
+//! A `Secret` may still be written to swap or a core dump. `SecretBox` keeps its value on pages of
+//! its own that are locked into memory and wiped on drop. On Linux, `memfd_secret` is used where
+//! available, and the pages are excluded from core dumps:
+//!
+//! ```ignore
+//! let key = SecretBox::try_from_vec(key_bytes)?;
+//! ```
+//!
+//!
 //! ## `no_std` support
 //!
 //! By disabling the default features, `no_std` is supported. It can be re-enabled through the `std`
 //! feature.
 //!
+//! Support for `String`, `Vec` and other heap-allocated types is available without the standard
+//! library through the `alloc` feature, which is implied by `std`.
+//!
 //!
 //! ## Additional traits
 //!
@@ -109,10 +510,30 @@
 //! the operation to the underlying type. These traits should be safe in a way that they will not
 //! accidentally leak the enclosed secret.
 //!
+//! Note that `PartialEq` is not constant-time. To check untrusted input against a secret, use
+//! `verify`, which is available on string and byte secrets without additional features:
+//!
+//! ```rust
+//! # use sec::Secret;
+//! let api_key = Secret::new("THIS-SHOULD-BE-SECRET");
+//! assert!(api_key.verify("THIS-SHOULD-BE-SECRET"));
+//! ```
+//!
+//! With the `subtle` feature, `subtle::ConstantTimeEq` is implemented for `Secret<T>` where
+//! `T: ConstantTimeEq`, covering integers and byte arrays. `Secret<String>`, `Secret<&str>` and
+//! `Secret<Vec<u8>>` offer an inherent `ct_eq` method comparing their byte representation instead.
+//! In all cases, secrets of different lengths compare unequal without examining their contents.
+//!
 //! Additional, by enabling the `ord` feature, the `PartialOrd` and `Ord` traits will be
 //! implemented. Since ordering could potentially leak information when a collection order by a
 //! Secret is printed in-order, these are opt-in by default.
 //!
+//! If secrets are only ordered to be used as keys of a `BTreeMap` or `BTreeSet`, the `hashed-ord`
+//! feature is the safer choice. It orders secrets by a stable hash of their value instead, so
+//! iterating a collection in order does not reveal which values are larger. Values with colliding
+//! hashes are still ordered by value, which requires `T: Hash + Ord`. The `ord` and `hashed-ord`
+//! features are mutually exclusive.
+//!
 //!
 //! ## Security
 //!
@@ -127,6 +548,14 @@
 //! supporting this pattern. It is not written to protect your GPG private key from core dumps, but
 //! rather login tokens from accidental disclosure.
 //!
+//! As a cheap opt-in, the `zeroize` feature implements `zeroize::Zeroize` and
+//! `zeroize::ZeroizeOnDrop` for `Secret<T>`, passing through to the held value. A
+//! `Secret<Zeroizing<Vec<u8>>>` is wiped when dropped, but copies made before, e.g. by a `Vec`
+//! growing, are not covered.
+//!
+//! With both `zeroize` and `alloc`, the `SecretString` and `SecretBytes` types wrap a
+//! `Secret<String>` or `Secret<Vec<u8>>` and are guaranteed to be wiped on drop.
+//!
 //! If protecting cryptographic secrets in-memory from stackdumps and similar is a concern, have a
 //! look at the [secrets] (https://crates.io/crates/secrets), [secstr]
 //! (https://crates.io/crates/secstr) or similar crates.
@@ -140,14 +569,214 @@ extern crate diesel;
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[cfg(feature = "serde")]
 extern crate serde;
 
+#[cfg(all(feature = "danger-reveal-debug", not(debug_assertions)))]
+compile_error!(
+    "the `danger-reveal-debug` feature prints secrets in `Debug` output and must not be enabled \
+     in builds without debug assertions"
+);
+
+#[cfg(all(feature = "ord", feature = "hashed-ord"))]
+compile_error!("the `ord` and `hashed-ord` features are mutually exclusive");
+
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_use]
+pub mod assert;
+#[cfg(feature = "std")]
+mod arena;
+mod array;
+mod audit;
+#[cfg(feature = "alloc")]
+mod builder;
+#[cfg(feature = "capability")]
+mod capability;
+#[cfg(feature = "cmp-plain")]
+mod cmp;
+#[cfg(feature = "alloc")]
+mod collections;
+#[cfg(all(feature = "anyhow", feature = "std"))]
+mod context;
+mod convert;
+#[cfg(feature = "alloc")]
+mod credentials;
+mod ct;
+#[cfg(feature = "hkdf")]
+mod derive;
+#[cfg(all(feature = "url", feature = "std"))]
+mod dsn;
+#[cfg(any(all(feature = "alloc", feature = "base64"), feature = "hex"))]
+mod encoding;
+#[cfg(feature = "encrypt")]
+mod encrypted;
+mod error;
+#[cfg(feature = "std")]
+mod expiring;
+#[cfg(feature = "std")]
+mod ffi;
+#[cfg(all(feature = "alloc", feature = "sha2"))]
+mod fingerprint;
+#[cfg(feature = "secrecy")]
+mod interop;
+mod iter;
+mod keyed;
+#[cfg(all(feature = "keyring", feature = "std"))]
+pub mod keyring;
+mod layout;
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_use]
+pub mod format;
+#[cfg(feature = "hashed-ord")]
+mod hashed_ord;
+#[cfg(feature = "std")]
+mod lazy;
+#[cfg(all(feature = "mlock", any(unix, windows)))]
+mod locked;
+#[cfg(feature = "std")]
+mod matcher;
+#[cfg(feature = "obfuscate")]
+#[doc(hidden)]
+#[macro_use]
+pub mod obfuscate;
+#[cfg(feature = "alloc")]
+mod mask;
+mod once;
+#[cfg(feature = "ops")]
+mod ops;
+mod option;
+#[cfg(feature = "std")]
+mod os;
+#[cfg(feature = "std")]
+mod panic_hook;
+mod parse;
+#[cfg(feature = "std")]
+mod persist;
+#[cfg(feature = "std")]
+mod process;
+#[cfg(feature = "prompt")]
+mod prompt;
+#[cfg(feature = "std")]
+pub mod provider;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "std")]
+mod redact;
+#[cfg(feature = "std")]
+pub mod registry;
+mod rotating;
+#[cfg(feature = "std")]
+mod scrub;
+#[cfg(all(feature = "subtle", feature = "std"))]
+mod set;
+#[cfg(feature = "std")]
+mod shared;
+#[cfg(feature = "std")]
+#[macro_use]
+mod source;
+#[cfg(feature = "stats")]
+pub mod stats;
+#[cfg(feature = "zxcvbn")]
+mod strength;
+#[cfg(feature = "std")]
+pub mod systemd;
 #[cfg(test)]
 mod tests;
+#[cfg(all(feature = "rand", feature = "alloc"))]
+pub mod token;
+mod tuple;
+#[cfg(feature = "alloc")]
+mod unique;
+#[cfg(feature = "alloc")]
+mod utf8;
+#[cfg(feature = "watch")]
+mod watched;
+#[cfg(feature = "zeroize")]
+mod wipe;
+
+#[cfg(feature = "std")]
+pub use arena::{ArenaSecret, SecretArena};
+pub use array::LengthError;
+#[cfg(feature = "audit")]
+pub use audit::{clear_reveal_hook, set_reveal_hook, RevealHook};
+#[cfg(feature = "alloc")]
+pub use builder::SecretBuilder;
+#[cfg(feature = "capability")]
+pub use capability::RevealToken;
+#[cfg(all(feature = "anyhow", feature = "std"))]
+pub use context::SecContextExt;
+pub use convert::IntoSecret;
+#[cfg(feature = "alloc")]
+pub use credentials::Credentials;
+#[cfg(all(feature = "alloc", feature = "base64"))]
+pub use encoding::DecodeError;
+#[cfg(feature = "hex")]
+pub use encoding::HexError;
+#[cfg(feature = "encrypt")]
+pub use encrypted::{EncryptedSecret, OpenError, ProcessKey};
+pub use error::{DecodeKind, Error};
+#[cfg(feature = "std")]
+pub use expiring::ExpiringSecret;
+#[cfg(feature = "std")]
+pub use ffi::NulSecretError;
+pub use iter::SecretIter;
+#[cfg(feature = "std")]
+pub use lazy::LazySecret;
+#[cfg(all(feature = "mlock", any(unix, windows)))]
+pub use locked::{LockError, Protections, SecretBox, SecretBoxMut, SecretBoxRef};
+pub use once::SecretOnce;
+pub use option::OptionSecretExt;
+#[cfg(feature = "std")]
+pub use panic_hook::install_scrubbing_panic_hook;
+pub use parse::ParseError;
+#[cfg(feature = "std")]
+pub use process::SecretCommandExt;
+#[cfg(feature = "prompt")]
+pub use prompt::{prompt, prompt_confirm};
+#[cfg(feature = "std")]
+pub use redact::{RedactErr, Redacted, SecretError};
+pub use rotating::RotatingSecret;
+#[cfg(feature = "std")]
+pub use scrub::ScrubWriter;
+#[cfg(all(feature = "subtle", feature = "std"))]
+pub use set::SecretSet;
+#[cfg(feature = "std")]
+pub use shared::SharedSecret;
+#[cfg(feature = "std")]
+pub use source::{EnvError, DEFAULT_FILE_LIMIT};
+#[cfg(feature = "zxcvbn")]
+pub use strength::{CrackTimes, StrengthReport};
+pub use tuple::{RevealPart, RevealTuple};
+#[cfg(feature = "alloc")]
+pub use unique::UniqueSecret;
+#[cfg(feature = "alloc")]
+pub use utf8::Utf8SecretError;
+#[cfg(feature = "watch")]
+pub use watched::{WatchHandle, WatchedSecret};
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+pub use wipe::{SecretBytes, SecretString};
+
+/// Extension traits, meant to be glob-imported with `use sec::prelude::*`
+pub mod prelude {
+    #[cfg(feature = "std")]
+    pub use super::RedactErr;
+    #[cfg(all(feature = "anyhow", feature = "std"))]
+    pub use super::SecContextExt;
+    pub use super::{IntoSecret, OptionSecretExt, RevealTuple, Secret};
+}
 
 use core::fmt;
 use core::hash::{Hash, Hasher};
+#[cfg(feature = "alloc")]
+use core::iter::FromIterator;
+use core::marker::PhantomData;
+use core::mem;
+use core::ops::Deref;
 
 #[cfg(feature = "ord")]
 use core::cmp::Ordering;
@@ -155,149 +784,933 @@ use core::cmp::Ordering;
 #[cfg(feature = "diesel")]
 use std::io::Write;
 
-#[cfg(feature = "std")]
-use std::string::String;
+#[cfg(feature = "alloc")]
+use alloc::borrow::{Cow, ToOwned};
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 #[cfg(feature = "serde")]
-use serde::{de::Error, Deserializer, Serializer};
+use serde::{de, de::Error as _, Deserializer, Serializer};
 
 #[cfg(feature = "rocket")]
 use rocket::form::FromFormField;
 #[cfg(feature = "rocket")]
-use std::{boxed::Box, future::Future, pin::Pin};
+use std::{future::Future, pin::Pin};
 
 /// Wraps a type `T`, preventing it from being accidentally revealed.
-pub struct Secret<T>(T);
+///
+/// `Secret<T>` is guaranteed to have the same memory layout as `T`.
+///
+/// `P` selects the `Placeholder` printed by `Debug`, see `Ellipsis` for the default.
+///
+/// `T` may be unsized, which allows borrowing a `Secret<String>` as a `&Secret<str>`.
+#[repr(transparent)]
+pub struct Secret<T: ?Sized, P = Ellipsis>(PhantomData<P>, T);
 
-#[cfg(feature = "std")]
-impl Secret<String> {
+#[cfg(feature = "alloc")]
+impl<P> Secret<String, P> {
     /// Returns a `str` reference, wrapped in a secret
     #[inline]
-    pub fn as_str(&self) -> Secret<&str> {
-        Secret(self.0.as_str())
+    pub fn as_str(&self) -> Secret<&str, P> {
+        self.as_deref()
     }
 
     /// Returns and **reveal** a `str` reference.
     #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `reveal_with` and a `RevealToken`")
+    )]
     pub fn reveal_str(&self) -> &str {
-        self.0.as_str()
+        audit::revealed::<String>();
+        self.1.as_str()
     }
-}
 
-impl<T> Secret<T> {
-    /// Creates a new secret
+    /// Returns the bytes of the held string, wrapped in a secret
     #[inline]
-    pub fn new(val: T) -> Secret<T> {
-        Secret(val)
+    pub fn as_bytes(&self) -> Secret<&[u8], P> {
+        Secret(PhantomData, self.1.as_bytes())
     }
 
-    /// Creates a secret immutable reference
+    /// Returns the length of the held string in bytes
+    ///
+    /// This is one of the few deliberate exceptions of a method revealing information about the
+    /// secret without `reveal` in its name. Be aware that the length of a secret can be sensitive
+    /// as well, e.g. for short passwords.
     #[inline]
-    pub fn as_ref(&self) -> Secret<&T> {
-        Secret(&self.0)
+    pub fn len(&self) -> usize {
+        self.1.len()
     }
 
-    /// Creates a secret mutable reference
+    /// Returns whether the held string is empty
     #[inline]
-    pub fn as_mut(&mut self) -> Secret<&mut T> {
-        Secret(&mut self.0)
+    pub fn is_empty(&self) -> bool {
+        self.1.is_empty()
     }
 
-    /// **Reveals** the held value by returning a reference
+    /// Splits the held string at the first occurrence of `delim`, wrapping both parts in secrets
+    ///
+    /// Useful for credentials passed as a single value, such as `user:password`. Returns `None` if
+    /// `delim` does not occur.
     #[inline]
-    pub fn reveal(&self) -> &T {
-        &self.0
+    #[allow(clippy::type_complexity)]
+    pub fn split_once_secret(&self, delim: char) -> Option<(Secret<&str, P>, Secret<&str, P>)> {
+        self.1
+            .split_once(delim)
+            .map(|(left, right)| (Secret(PhantomData, left), Secret(PhantomData, right)))
     }
 
-    /// **Reveals** the held value by unwrapping
+    /// Splits the held string at every occurrence of `delim`, wrapping all parts in secrets
     #[inline]
-    pub fn reveal_into(self) -> T {
-        self.0
+    pub fn split_secret(&self, delim: char) -> impl Iterator<Item = Secret<&str, P>> {
+        self.1.split(delim).map(|part| Secret(PhantomData, part))
     }
 
-    /// **Reveals** the held value by applying a function to it
+    /// Removes leading and trailing whitespace, including newlines, from the held string in place
     #[inline]
-    pub fn map_revealed<V, F: FnOnce(T) -> V>(self, f: F) -> Secret<V> {
-        Secret(f(self.0))
+    pub fn trim_in_place(&mut self) {
+        let end = self.1.trim_end().len();
+        self.1.truncate(end);
+        let start = self.1.len() - self.1.trim_start().len();
+        self.1.drain(..start);
     }
-}
 
-impl<T> fmt::Debug for Secret<T> {
+    /// Removes `prefix` from the start of the held string in place, if present
+    ///
+    /// Returns whether the prefix was removed, e.g. when stripping `Bearer ` from a header value.
     #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "...")
+    pub fn strip_prefix_in_place(&mut self, prefix: &str) -> bool {
+        if !self.1.starts_with(prefix) {
+            return false;
+        }
+
+        self.1.drain(..prefix.len());
+        true
     }
 }
 
-impl<T: Clone> Clone for Secret<T> {
+#[cfg(feature = "alloc")]
+impl<'a, P> Secret<Cow<'a, str>, P> {
+    /// Returns a `str` reference, wrapped in a secret
+    #[inline]
+    pub fn as_str(&self) -> Secret<&str, P> {
+        self.as_deref()
+    }
+
+    /// Returns and **reveal** a `str` reference.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `reveal_with` and a `RevealToken`")
+    )]
+    pub fn reveal_str(&self) -> &str {
+        audit::revealed::<Cow<'a, str>>();
+        &self.1
+    }
+
+    /// Converts the held value into an owned secret `String`, cloning it if it is borrowed
+    #[inline]
+    pub fn into_owned(self) -> Secret<String, P> {
+        Secret::wrap(self.1.into_owned())
+    }
+}
+
+impl<P> Secret<&str, P> {
+    /// Returns the bytes of the held string, wrapped in a secret
+    #[inline]
+    pub fn as_bytes(&self) -> Secret<&[u8], P> {
+        Secret(PhantomData, self.1.as_bytes())
+    }
+
+    /// Returns the length of the held string in bytes
+    ///
+    /// See `Secret<String>::len` for caveats.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.1.len()
+    }
+
+    /// Returns whether the held string is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.1.is_empty()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Secret<Vec<u8>> {
+    /// Creates a new secret from anything convertible into bytes
+    #[inline]
+    pub fn from_bytes<B: Into<Vec<u8>>>(bytes: B) -> Secret<Vec<u8>> {
+        Secret::wrap(bytes.into())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> Secret<Vec<u8>, P> {
+    /// Returns a byte slice, wrapped in a secret
+    #[inline]
+    pub fn as_slice(&self) -> Secret<&[u8], P> {
+        Secret(PhantomData, self.1.as_slice())
+    }
+
+    /// Returns and **reveal** a byte slice.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `reveal_with` and a `RevealToken`")
+    )]
+    pub fn reveal_bytes(&self) -> &[u8] {
+        audit::revealed::<Vec<u8>>();
+        self.1.as_slice()
+    }
+
+    /// Returns the number of held bytes
+    ///
+    /// See `Secret<String>::len` for caveats.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.1.len()
+    }
+
+    /// Returns whether no bytes are held
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.1.is_empty()
+    }
+}
+
+impl<P> Secret<&[u8], P> {
+    /// Returns and **reveal** the held byte slice.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `reveal_with` and a `RevealToken`")
+    )]
+    pub fn reveal_bytes(&self) -> &[u8] {
+        audit::revealed::<&[u8]>();
+        self.1
+    }
+
+    /// Copies the held bytes into a new secret `Vec`
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn to_vec_secret(&self) -> Secret<Vec<u8>, P> {
+        Secret::wrap(self.1.to_vec())
+    }
+
+    /// Returns the number of held bytes
+    ///
+    /// See `Secret<String>::len` for caveats.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.1.len()
+    }
+
+    /// Returns whether no bytes are held
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.1.is_empty()
+    }
+}
+
+impl<T> Secret<T> {
+    /// Creates a new secret
+    ///
+    /// This is a `const fn`, so it can be used to create secrets in `const` and `static` items,
+    /// unlike the `From<T>` implementation. For the same reason, secrets created with `new` are
+    /// not counted by the `stats` feature.
+    #[inline]
+    pub const fn new(val: T) -> Secret<T> {
+        Secret(PhantomData, val)
+    }
+}
+
+impl<T, P> Secret<T, P> {
+    /// Creates a new secret that is printed using the placeholder `P`
+    ///
+    /// The placeholder usually has to be named explicitly, e.g.
+    /// `Secret::<_, Filtered>::with_placeholder(token)`. Like `new`, this is a `const fn` and not
+    /// counted by the `stats` feature.
+    #[inline]
+    pub const fn with_placeholder(val: T) -> Secret<T, P> {
+        Secret(PhantomData, val)
+    }
+
+    /// Changes the placeholder to `Q`, keeping the held value
+    ///
+    /// Constructors such as `from_env` or `random` always use the default placeholder, as a
+    /// placeholder can not be inferred from their arguments.
+    #[inline]
+    pub fn into_placeholder<Q>(self) -> Secret<T, Q> {
+        Secret(PhantomData, self.1)
+    }
+
+    /// Wraps `val`, counting the creation with the `stats` feature
+    ///
+    /// All non-`const` constructors and conversions of this crate create secrets through `wrap`.
+    #[inline]
+    pub(crate) fn wrap(val: T) -> Secret<T, P> {
+        #[cfg(feature = "stats")]
+        stats::created::<T>();
+        Secret(PhantomData, val)
+    }
+
+    /// **Reveals** the held value by unwrapping
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `reveal_into_with` and a `RevealToken`")
+    )]
+    pub fn reveal_into(self) -> T {
+        audit::revealed::<T>();
+        self.1
+    }
+
+    /// **Reveals** the held value by applying a function to it
+    #[inline]
+    pub fn map_revealed<V, F: FnOnce(T) -> V>(self, f: F) -> Secret<V, P> {
+        Secret::wrap(f(self.1))
+    }
+
+    /// **Reveals** the held value by applying a fallible function to it
+    ///
+    /// On success, the result is wrapped in a new secret. The error is returned as-is, so if `E`
+    /// embeds the input (as many parse errors do), the secret leaks through it. Use
+    /// `try_map_revealed_redacted` to replace the error in that case.
+    #[inline]
+    pub fn try_map_revealed<V, E, F: FnOnce(T) -> Result<V, E>>(
+        self,
+        f: F,
+    ) -> Result<Secret<V, P>, E> {
+        f(self.1).map(Secret::wrap)
+    }
+
+    /// **Reveals** the held value by applying a fallible function to it, redacting errors
+    ///
+    /// Like `try_map_revealed`, but any error is passed through `redact` before being returned.
+    #[inline]
+    pub fn try_map_revealed_redacted<V, E, R, F, G>(
+        self,
+        f: F,
+        redact: G,
+    ) -> Result<Secret<V, P>, R>
+    where
+        F: FnOnce(T) -> Result<V, E>,
+        G: FnOnce(E) -> R,
+    {
+        f(self.1).map(Secret::wrap).map_err(redact)
+    }
+
+    /// Combines two secrets into a secret tuple
+    #[inline]
+    pub fn zip<U>(self, other: Secret<U, P>) -> Secret<(T, U), P> {
+        Secret::wrap((self.1, other.1))
+    }
+
+    /// Combines two secrets into a new secret by applying a function to both values
+    #[inline]
+    pub fn zip_with<U, V, F: FnOnce(T, U) -> V>(self, other: Secret<U, P>, f: F) -> Secret<V, P> {
+        Secret::wrap(f(self.1, other.1))
+    }
+
+    /// Replaces the held value, returning the old value as a secret
+    ///
+    /// On a `Secret<Option<T>>`, `replace` wraps the value in `Some` instead.
+    #[inline]
+    pub fn replace_value(&mut self, value: T) -> Secret<T, P> {
+        Secret::wrap(mem::replace(&mut self.1, value))
+    }
+
+    /// Swaps the values of two secrets
+    #[inline]
+    pub fn swap(&mut self, other: &mut Secret<T, P>) {
+        mem::swap(&mut self.1, &mut other.1)
+    }
+}
+
+impl<T: Default, P> Secret<T, P> {
+    /// Takes the held value out of the secret, leaving the default value in its place
+    ///
+    /// For a `Secret<String>`, an empty string is left behind; for a `Secret<Option<T>>`, `None`,
+    /// as with `take`.
+    #[inline]
+    pub fn take_value(&mut self) -> Secret<T, P> {
+        Secret::wrap(mem::take(&mut self.1))
+    }
+}
+
+impl<T: ?Sized, P> Secret<T, P> {
+    /// Creates a secret immutable reference
+    #[inline]
+    pub const fn as_ref(&self) -> Secret<&T, P> {
+        Secret(PhantomData, &self.1)
+    }
+
+    /// Creates a secret mutable reference
+    #[inline]
+    pub fn as_mut(&mut self) -> Secret<&mut T, P> {
+        Secret(PhantomData, &mut self.1)
+    }
+
+    /// **Reveals** the held value by returning a reference
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `reveal_with` and a `RevealToken`")
+    )]
+    pub fn reveal(&self) -> &T {
+        audit::revealed::<T>();
+        &self.1
+    }
+
+    /// **Reveals** the held value by returning a reference, in `const` contexts
+    ///
+    /// Unlike `reveal`, this is **not** reported to the `audit` hook or counted by `stats`, as a
+    /// `const fn` can call neither. Prefer `reveal` everywhere else.
+    #[inline]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `reveal_with` and a `RevealToken`")
+    )]
+    pub const fn reveal_const(&self) -> &T {
+        &self.1
+    }
+
+    /// **Reveals** the held value by returning a mutable reference
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `reveal_mut_with` and a `RevealToken`")
+    )]
+    pub fn reveal_mut(&mut self) -> &mut T {
+        audit::revealed::<T>();
+        &mut self.1
+    }
+
+    /// **Reveals** a reference to the held value to a function, wrapping the result
+    ///
+    /// Unlike `map_revealed`, this does not consume the secret, which allows projecting a field,
+    /// e.g. `config.map_ref(|c| c.db_password.as_str())`.
+    #[inline]
+    pub fn map_ref<'a, V, F: FnOnce(&'a T) -> V>(&'a self, f: F) -> Secret<V, P> {
+        Secret::wrap(f(&self.1))
+    }
+
+    /// **Reveals** a mutable reference to the held value to a function, wrapping the result
+    #[inline]
+    pub fn map_mut<'a, V, F: FnOnce(&'a mut T) -> V>(&'a mut self, f: F) -> Secret<V, P> {
+        Secret::wrap(f(&mut self.1))
+    }
+
+    /// **Reveals** the held value to a closure, returning its result
+    ///
+    /// The closure is the trust boundary: the reference handed to it should not escape it, and the
+    /// result is returned as-is, not wrapped in a `Secret`. Only return values derived from the
+    /// secret that are fine to be seen, e.g. a request object that has been authorized with it.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `reveal_with` and a `RevealToken`")
+    )]
+    pub fn with_revealed<R, F: FnOnce(&T) -> R>(&self, f: F) -> R {
+        audit::revealed::<T>();
+        f(&self.1)
+    }
+
+    /// **Reveals** the held value mutably to a closure, returning its result
+    ///
+    /// See `with_revealed` for the caveats regarding the returned value.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `reveal_mut_with` and a `RevealToken`")
+    )]
+    pub fn with_revealed_mut<R, F: FnOnce(&mut T) -> R>(&mut self, f: F) -> R {
+        audit::revealed::<T>();
+        f(&mut self.1)
+    }
+
+    /// Modifies the held value in place through a closure
+    ///
+    /// Meant for normalizing a secret, e.g. trimming or lowercasing it, without unwrapping and
+    /// re-wrapping it, which would leave the intermediate value exposed.
+    #[inline]
+    pub fn mutate_revealed<F: FnOnce(&mut T)>(&mut self, f: F) {
+        f(&mut self.1)
+    }
+}
+
+impl<T: Deref + ?Sized, P> Secret<T, P> {
+    /// Creates a secret reference to the dereferenced value
+    ///
+    /// This turns a `Secret<Vec<u8>>` into a `Secret<&[u8]>`, a `Secret<PathBuf>` into a
+    /// `Secret<&Path>` and so on. Note that `Secret` itself intentionally does not implement
+    /// `Deref`, unless the `danger-deref` feature is enabled.
+    #[inline]
+    pub fn as_deref(&self) -> Secret<&T::Target, P> {
+        Secret(PhantomData, self.1.deref())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, P> Secret<T, P> {
+    /// Moves the secret into an `Arc`, for sharing it between threads or tasks
+    #[inline]
+    pub fn into_arc(self) -> Arc<Secret<T, P>> {
+        Arc::new(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: 'static, P: 'static> Secret<T, P> {
+    /// Moves the secret to the heap and leaks it, returning a reference valid for the rest of the
+    /// program
+    ///
+    /// Meant for secrets loaded once at startup and shared through `&'static` state, e.g. with web
+    /// frameworks. The value is never dropped, so it is not wiped either, even with `zeroize`.
+    #[inline]
+    pub fn leak(self) -> &'static Secret<T, P> {
+        Box::leak(Box::new(self))
+    }
+
+    /// **Reveals** the held value by leaking it, returning a reference valid for the rest of the
+    /// program
+    ///
+    /// See `leak` for caveats. Prefer `leak` to keep the value wrapped.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `reveal_into_with` and a `RevealToken`")
+    )]
+    pub fn reveal_leak(self) -> &'static T {
+        audit::revealed::<T>();
+        Box::leak(Box::new(self.1))
+    }
+}
+
+impl<T: Clone, P> Secret<T, P> {
+    /// **Reveals** the held value by cloning it
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `reveal_with` and a `RevealToken`")
+    )]
+    pub fn reveal_cloned(&self) -> T {
+        audit::revealed::<T>();
+        self.1.clone()
+    }
+}
+
+impl<T: Clone, P> Secret<&T, P> {
+    /// Clones the referenced value into a new secret
+    ///
+    /// Since `&T` is `Clone` itself, `reveal_cloned` on a `Secret<&T>` only copies the reference.
+    /// To get an owned value out of a secret reference, use `reveal_owned`.
+    #[inline]
+    pub fn cloned(self) -> Secret<T, P> {
+        Secret::wrap(self.1.clone())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ToOwned + ?Sized, P> Secret<&T, P> {
+    /// **Reveals** the referenced value by converting it into an owned value
+    ///
+    /// Unlike `reveal_cloned`, which returns a copy of the reference, this returns an owned value,
+    /// e.g. a `T` from `secret.as_ref().reveal_owned()` or a `String` from a `Secret<&str>`.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `reveal_with` and a `RevealToken`")
+    )]
+    pub fn reveal_owned(&self) -> T::Owned {
+        audit::revealed::<&T>();
+        self.1.to_owned()
+    }
+}
+
+impl<T, P> Secret<Option<T>, P> {
+    /// Transposes a secret `Option` into an `Option` of a secret
+    ///
+    /// The presence of a value is not considered secret. The reverse conversion is available
+    /// through `From<Option<Secret<T>>>`.
+    #[inline]
+    pub fn transpose(self) -> Option<Secret<T, P>> {
+        self.1.map(Secret::wrap)
+    }
+
+    /// Takes the value out of the secret `Option`, leaving `None` in its place
+    #[inline]
+    pub fn take(&mut self) -> Secret<Option<T>, P> {
+        Secret::wrap(self.1.take())
+    }
+
+    /// Replaces the value in the secret `Option`, returning the old value as a secret
+    #[inline]
+    pub fn replace(&mut self, value: T) -> Option<Secret<T, P>> {
+        self.1.replace(value).map(Secret::wrap)
+    }
+
+    /// Inserts a value computed from `f` if the secret `Option` is `None`, then returns a secret
+    /// mutable reference to the contained value
+    #[inline]
+    pub fn get_or_insert_with<F: FnOnce() -> T>(&mut self, f: F) -> Secret<&mut T, P> {
+        Secret(PhantomData, self.1.get_or_insert_with(f))
+    }
+
+    /// Returns whether the secret `Option` holds a value
+    ///
+    /// Like `transpose`, this does not treat presence as secret.
+    #[inline]
+    pub fn is_some(&self) -> bool {
+        self.1.is_some()
+    }
+
+    /// Returns whether the secret `Option` is `None`
+    #[inline]
+    pub fn is_none(&self) -> bool {
+        self.1.is_none()
+    }
+
+    /// Applies a function to the contained value, if any, keeping the result secret
+    #[inline]
+    pub fn map_inner<V, F: FnOnce(T) -> V>(self, f: F) -> Secret<Option<V>, P> {
+        Secret::wrap(self.1.map(f))
+    }
+
+    /// Applies a function returning an `Option` to the contained value, if any, keeping the result
+    /// secret
+    #[inline]
+    pub fn and_then_inner<V, F: FnOnce(T) -> Option<V>>(self, f: F) -> Secret<Option<V>, P> {
+        Secret::wrap(self.1.and_then(f))
+    }
+
+    /// Keeps the contained value only if `predicate` returns `true` for it
+    ///
+    /// Whether the result is `None` reveals the outcome of the predicate.
+    #[inline]
+    pub fn filter_inner<F: FnOnce(&T) -> bool>(self, predicate: F) -> Secret<Option<T>, P> {
+        Secret::wrap(self.1.filter(predicate))
+    }
+
+    /// Returns the contained value as a secret, or a secret holding `default` if there is none
+    #[inline]
+    pub fn unwrap_or_secret(self, default: T) -> Secret<T, P> {
+        Secret::wrap(self.1.unwrap_or(default))
+    }
+}
+
+impl<T, E, P> Secret<Result<T, E>, P> {
+    /// Transposes a secret `Result` into a `Result` of a secret
+    ///
+    /// **IMPORTANT**: The error is returned unwrapped. If `E` embeds the input that caused it, the
+    /// secret leaks through it; use `transpose_err` to keep the error wrapped as well.
+    #[inline]
+    pub fn transpose(self) -> Result<Secret<T, P>, E> {
+        self.1.map(Secret::wrap)
+    }
+
+    /// Transposes a secret `Result` into a `Result` of secrets, keeping the error secret
+    #[inline]
+    pub fn transpose_err(self) -> Result<Secret<T, P>, Secret<E, P>> {
+        self.1.map(Secret::wrap).map_err(Secret::wrap)
+    }
+}
+
+impl<A, B, P> Secret<(A, B), P> {
+    /// Splits a secret pair into two secrets
+    #[inline]
+    pub fn unzip(self) -> (Secret<A, P>, Secret<B, P>) {
+        let (a, b) = self.1;
+        (Secret::wrap(a), Secret::wrap(b))
+    }
+}
+
+impl<A, B, C, P> Secret<(A, B, C), P> {
+    /// Splits a secret triple into three secrets
+    #[inline]
+    pub fn unzip(self) -> (Secret<A, P>, Secret<B, P>, Secret<C, P>) {
+        let (a, b, c) = self.1;
+        (Secret::wrap(a), Secret::wrap(b), Secret::wrap(c))
+    }
+}
+
+/// **Reveals** the held value on every dereference, see the `danger-deref` feature
+#[cfg(feature = "danger-deref")]
+impl<T: ?Sized, P> Deref for Secret<T, P> {
+    type Target = T;
+
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn deref(&self) -> &T {
+        audit::revealed::<T>();
+        &self.1
+    }
+}
+
+#[cfg(not(feature = "danger-reveal-debug"))]
+impl<T: ?Sized, P: Placeholder> fmt::Debug for Secret<T, P> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        P::fmt_placeholder::<T>(f)
+    }
+}
+
+/// **Reveals** the held value, see the `danger-reveal-debug` feature
+#[cfg(feature = "danger-reveal-debug")]
+impl<T: fmt::Debug + ?Sized, P> fmt::Debug for Secret<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Secret").field(&&self.1).finish()
+    }
+}
+
+// The numeric formatting traits always print the placeholder, even with `danger-reveal-debug`.
+// They are implemented regardless of `T`, so that secrets satisfy generic bounds such as
+// `T: LowerHex` without any format specifier being able to show the value.
+macro_rules! impl_redacted_fmt {
+    ($($fmt:ident),*) => {
+        $(
+            impl<T: ?Sized, P: Placeholder> fmt::$fmt for Secret<T, P> {
+                #[inline]
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    P::fmt_placeholder::<T>(f)
+                }
+            }
+        )*
+    };
+}
+
+impl_redacted_fmt!(LowerHex, UpperHex, Octal, Binary, LowerExp, UpperExp);
+
+/// Placeholder printed by the `Debug` implementation of a `Secret` instead of the held value
+///
+/// The default placeholder is `Ellipsis`. Custom placeholders are selected through the second type
+/// parameter of `Secret`:
+///
+/// ```rust
+/// use sec::{Placeholder, Secret};
+///
+/// struct Filtered;
+///
+/// impl Placeholder for Filtered {
+///     const PLACEHOLDER: &'static str = "[FILTERED]";
+/// }
+///
+/// let token: Secret<&str, Filtered> = Secret::with_placeholder("THIS-SHOULD-BE-SECRET");
+/// assert_eq!(format!("{:?}", token), "[FILTERED]");
+/// ```
+///
+/// Methods and conversions keep the placeholder, e.g. `map_revealed` or `decode_hex` on a
+/// `Secret<T, Filtered>` return a `Secret<U, Filtered>`. Constructors and conversions from plain
+/// values, such as `From<T>`, `Default` and `FromStr`, use the default placeholder, so that they
+/// need no type annotations; `with_placeholder` and `into_placeholder` select another one.
+///
+/// A placeholder only ever gets to see the type of the held value, never the value itself.
+pub trait Placeholder {
+    /// Text printed in place of the held value
+    const PLACEHOLDER: &'static str;
+
+    /// Writes the placeholder for a secret holding a value of type `T`
+    ///
+    /// Writes `PLACEHOLDER` by default.
+    #[inline]
+    fn fmt_placeholder<T: ?Sized>(f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(Self::PLACEHOLDER)
+    }
+}
+
+/// The default placeholder, printing `...`
+///
+/// With the `typed-debug` feature, the type of the held value is printed as well, e.g.
+/// `Secret<String>(REDACTED)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Ellipsis;
+
+impl Placeholder for Ellipsis {
+    const PLACEHOLDER: &'static str = "...";
+
+    #[cfg(feature = "typed-debug")]
+    fn fmt_placeholder<T: ?Sized>(f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Secret<")?;
+        write_type_name(f, core::any::type_name::<T>())?;
+        f.write_str(">(REDACTED)")
+    }
+}
+
+/// Writes the type name `name` with all paths stripped
+///
+/// Turns `core::option::Option<alloc::string::String>` into `Option<String>`.
+#[cfg(feature = "typed-debug")]
+fn write_type_name(f: &mut fmt::Formatter, name: &str) -> fmt::Result {
+    let mut rest = name;
+    while !rest.is_empty() {
+        let end = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == ':'))
+            .unwrap_or(rest.len());
+        let (path, tail) = rest.split_at(end);
+        f.write_str(path.rsplit("::").next().unwrap_or(path))?;
+
+        let delim = tail.chars().next().map_or(0, char::len_utf8);
+        f.write_str(&tail[..delim])?;
+        rest = &tail[delim..];
+    }
+    Ok(())
+}
+
+impl<T: Clone, P> Clone for Secret<T, P> {
     #[inline]
     fn clone(&self) -> Self {
-        Secret(self.0.clone())
+        Secret::wrap(self.1.clone())
     }
 }
 
-impl<T: PartialEq> PartialEq for Secret<T> {
+/// Compares two secrets by comparing their held values
+///
+/// This delegates to `T::eq`, which for strings and byte buffers usually returns as soon as the
+/// first differing byte is found. **This is not constant-time**; use `verify` or `ConstantTimeEq`
+/// (`subtle` feature) when comparing untrusted input against a secret.
+impl<T: PartialEq + ?Sized, P> PartialEq for Secret<T, P> {
     #[inline]
-    fn eq(&self, other: &Secret<T>) -> bool {
-        self.0.eq(&other.0)
+    fn eq(&self, other: &Secret<T, P>) -> bool {
+        self.1.eq(&other.1)
     }
 }
 
+// Comparisons between owned and borrowed strings, mirroring those of `String` in `std`. Like the
+// above, these are not constant-time. There are none for byte buffers, so that comparisons with
+// secrets holding a `Vec<u8>` infer the type of the other side as before.
+macro_rules! impl_borrowed_eq {
+    ($($owned:ty, $borrowed:ty;)*) => {
+        $(
+            #[cfg(feature = "alloc")]
+            impl<'a, P> PartialEq<Secret<$borrowed, P>> for Secret<$owned, P> {
+                #[inline]
+                fn eq(&self, other: &Secret<$borrowed, P>) -> bool {
+                    self.1 == other.1
+                }
+            }
+
+            #[cfg(feature = "alloc")]
+            impl<'a, P> PartialEq<Secret<$owned, P>> for Secret<$borrowed, P> {
+                #[inline]
+                fn eq(&self, other: &Secret<$owned, P>) -> bool {
+                    self.1 == other.1
+                }
+            }
+        )*
+    };
+}
+
+impl_borrowed_eq! {
+    String, str;
+    String, &'a str;
+}
+
 #[cfg(feature = "ord")]
-impl<T: PartialOrd> PartialOrd for Secret<T> {
+impl<T: PartialOrd + ?Sized, P> PartialOrd for Secret<T, P> {
     #[inline]
-    fn partial_cmp(&self, other: &Secret<T>) -> Option<Ordering> {
-        self.0.partial_cmp(&other.0)
+    fn partial_cmp(&self, other: &Secret<T, P>) -> Option<Ordering> {
+        self.1.partial_cmp(&other.1)
     }
 }
 
 #[cfg(feature = "ord")]
-impl<T: Ord> Ord for Secret<T> {
+impl<T: Ord + ?Sized, P> Ord for Secret<T, P> {
     #[inline]
-    fn cmp(&self, other: &Secret<T>) -> Ordering {
-        self.0.cmp(&other.0)
+    fn cmp(&self, other: &Secret<T, P>) -> Ordering {
+        self.1.cmp(&other.1)
     }
 }
 
-impl<T: Hash> Hash for Secret<T> {
+impl<T: Hash + ?Sized, P> Hash for Secret<T, P> {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.0.hash(state);
+        self.1.hash(state);
     }
 }
 
 impl<T: Default> Default for Secret<T> {
     #[inline]
     fn default() -> Secret<T> {
-        Secret(T::default())
+        Secret::wrap(T::default())
     }
 }
 
-impl<T: Copy> Copy for Secret<T> {}
-impl<T: Eq> Eq for Secret<T> {}
-unsafe impl<T: Sync> Sync for Secret<T> {}
-unsafe impl<T: Send> Send for Secret<T> {}
+impl<T: Copy, P> Copy for Secret<T, P> {}
+impl<T: Eq + ?Sized, P> Eq for Secret<T, P> {}
+unsafe impl<T: Sync + ?Sized, P> Sync for Secret<T, P> {}
+unsafe impl<T: Send + ?Sized, P> Send for Secret<T, P> {}
 
 impl<T> From<T> for Secret<T> {
     #[inline]
     fn from(v: T) -> Secret<T> {
-        Secret(v)
+        Secret::wrap(v)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, P> FromIterator<Secret<T, P>> for Secret<Vec<T>, P> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Secret<T, P>>>(iter: I) -> Self {
+        Secret::wrap(iter.into_iter().map(|s| s.1).collect())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> FromIterator<T> for Secret<Vec<T>> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Secret::wrap(iter.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, P> Extend<Secret<T, P>> for Secret<Vec<T>, P> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Secret<T, P>>>(&mut self, iter: I) {
+        self.1.extend(iter.into_iter().map(|s| s.1))
+    }
+}
+
+impl<T, P> From<Option<Secret<T, P>>> for Secret<Option<T>, P> {
+    #[inline]
+    fn from(v: Option<Secret<T, P>>) -> Secret<Option<T>, P> {
+        Secret::wrap(v.map(|s| s.1))
     }
 }
 
 #[cfg(feature = "serde")]
-impl<T: serde::Serialize> serde::Serialize for Secret<T> {
+impl<T: serde::Serialize + ?Sized, P> serde::Serialize for Secret<T, P> {
     #[inline]
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        self.0.serialize(serializer)
+        self.1.serialize(serializer)
     }
 }
 
 #[cfg(feature = "serde")]
-impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Secret<T> {
+impl<'de, T: serde::Deserialize<'de>, P> serde::Deserialize<'de> for Secret<T, P> {
     #[inline]
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -305,7 +1718,7 @@ impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Secret<T> {
     {
         // we need to intercept the exception, as it might contain the actual
         // raw value being deserialized
-        match T::deserialize(deserializer).map(Secret) {
+        match T::deserialize(deserializer).map(Secret::wrap) {
             Err(_) => Err(D::Error::custom(
                 "a confidential value could not be deserialized",
             )),
@@ -314,10 +1727,60 @@ impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Secret<T> {
     }
 }
 
+/// Deserializes a `Secret<Cow<str>>`, borrowing from the input if the format allows it
+///
+/// The regular `Deserialize` implementation of `Cow<str>` always produces an owned value. Use this
+/// function through `deserialize_with` to avoid the copy:
+///
+/// ```ignore
+/// #[derive(Deserialize)]
+/// struct AuthRequest<'a> {
+///     #[serde(borrow, deserialize_with = "sec::deserialize_cow_str")]
+///     password: Secret<Cow<'a, str>>,
+/// }
+/// ```
+///
+/// Like for any other `Secret`, deserialization errors are replaced to avoid leaking the input.
+#[cfg(all(feature = "serde", feature = "alloc"))]
+pub fn deserialize_cow_str<'de, D>(deserializer: D) -> Result<Secret<Cow<'de, str>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct CowStrVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for CowStrVisitor {
+        type Value = Cow<'de, str>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a string")
+        }
+
+        fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+            Ok(Cow::Borrowed(v))
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            Ok(Cow::Owned(v.into()))
+        }
+
+        fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+            Ok(Cow::Owned(v))
+        }
+    }
+
+    match deserializer.deserialize_str(CowStrVisitor) {
+        Err(_) => Err(D::Error::custom(
+            "a confidential value could not be deserialized",
+        )),
+        Ok(v) => Ok(Secret::wrap(v)),
+    }
+}
+
 #[cfg(all(feature = "diesel", feature = "std"))]
-impl<A, DB, T> diesel::types::ToSql<A, DB> for Secret<T>
+impl<A, DB, T, P> diesel::types::ToSql<A, DB> for Secret<T, P>
 where
     T: diesel::types::ToSql<A, DB> + fmt::Debug,
+    P: Placeholder,
     DB: diesel::backend::Backend + diesel::types::HasSqlType<A>,
 {
     #[inline]
@@ -325,12 +1788,12 @@ where
         &self,
         out: &mut diesel::serialize::Output<W, DB>,
     ) -> Result<diesel::types::IsNull, std::boxed::Box<dyn std::error::Error + Send + Sync>> {
-        self.0.to_sql(out)
+        self.1.to_sql(out)
     }
 }
 
 #[cfg(all(feature = "diesel", feature = "std"))]
-impl<'a, E, T> diesel::expression::AsExpression<E> for &'a Secret<T>
+impl<'a, E, T, P> diesel::expression::AsExpression<E> for &'a Secret<T, P>
 where
     T: diesel::expression::AsExpression<E>,
     &'a T: diesel::expression::AsExpression<E>,
@@ -339,12 +1802,12 @@ where
 
     #[inline]
     fn as_expression(self) -> Self::Expression {
-        (&self.0).as_expression()
+        (&self.1).as_expression()
     }
 }
 
 #[cfg(all(feature = "diesel", feature = "std"))]
-impl<T, ST, DB> diesel::query_source::Queryable<ST, DB> for Secret<T>
+impl<T, ST, DB, P> diesel::query_source::Queryable<ST, DB> for Secret<T, P>
 where
     DB: diesel::backend::Backend + diesel::types::HasSqlType<ST>,
     T: diesel::query_source::Queryable<ST, DB>,
@@ -353,18 +1816,19 @@ where
 
     #[inline]
     fn build(row: Self::Row) -> Self {
-        Secret(T::build(row))
+        Secret::wrap(T::build(row))
     }
 }
 
 #[cfg(all(feature = "std", feature = "rocket"))]
-impl<'v, T> FromFormField<'v> for Secret<T>
+impl<'v, T, P> FromFormField<'v> for Secret<T, P>
 where
     T: FromFormField<'v>,
+    P: Send,
 {
     #[inline]
     fn from_value(field: rocket::form::ValueField<'v>) -> rocket::form::Result<'v, Self> {
-        <T as FromFormField>::from_value(field).map(Secret)
+        <T as FromFormField>::from_value(field).map(Secret::wrap)
     }
 
     #[inline]
@@ -376,11 +1840,15 @@ where
         'life0: 'async_trait,
         Self: 'async_trait,
     {
-        Box::pin(async move { <T as FromFormField>::from_data(field).await.map(Secret) })
+        Box::pin(async move {
+            <T as FromFormField>::from_data(field)
+                .await
+                .map(Secret::wrap)
+        })
     }
 
     #[inline]
     fn default() -> Option<Self> {
-        <T as FromFormField>::default().map(Secret)
+        <T as FromFormField>::default().map(Secret::wrap)
     }
 }
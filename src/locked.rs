@@ -0,0 +1,679 @@
+//! Heap storage that is locked against swapping
+
+use std::alloc::{self, Layout};
+#[cfg(all(windows, feature = "windows-protect"))]
+use std::convert::TryFrom;
+use std::error::Error;
+use std::ffi::c_void;
+use std::fmt;
+use std::io;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::ptr::{self, NonNull};
+use std::slice;
+#[cfg(all(windows, feature = "windows-protect"))]
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::vec::Vec;
+
+use zeroize::Zeroize;
+
+use super::audit;
+
+/// Error returned when memory could not be locked against swapping
+///
+/// On Unix, this is usually caused by exceeding `RLIMIT_MEMLOCK`, which is as low as 64 KiB on
+/// some systems.
+#[derive(Debug)]
+pub struct LockError(io::Error);
+
+impl LockError {
+    /// Returns the error reported by the operating system
+    #[inline]
+    pub fn os_error(&self) -> &io::Error {
+        &self.0
+    }
+}
+
+impl fmt::Display for LockError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "could not lock memory: {}", self.0)
+    }
+}
+
+impl Error for LockError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+#[cfg(unix)]
+fn page_size() -> usize {
+    // cannot fail for `_SC_PAGESIZE`
+    unsafe { libc::sysconf(libc::_SC_PAGESIZE) as usize }
+}
+
+#[cfg(windows)]
+fn page_size() -> usize {
+    use windows_sys::Win32::System::SystemInformation::{GetSystemInfo, SYSTEM_INFO};
+
+    let mut info: SYSTEM_INFO = unsafe { mem::zeroed() };
+    unsafe { GetSystemInfo(&mut info) };
+    info.dwPageSize as usize
+}
+
+#[cfg(unix)]
+fn lock(ptr: *const u8, size: usize) -> io::Result<()> {
+    if unsafe { libc::mlock(ptr as *const c_void, size) } == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+#[cfg(windows)]
+fn lock(ptr: *const u8, size: usize) -> io::Result<()> {
+    if unsafe { windows_sys::Win32::System::Memory::VirtualLock(ptr as *const c_void, size) } != 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+#[cfg(all(unix, not(target_os = "linux")))]
+fn unlock(ptr: *const u8, size: usize) {
+    unsafe { libc::munlock(ptr as *const c_void, size) };
+}
+
+#[cfg(windows)]
+fn unlock(ptr: *const u8, size: usize) {
+    unsafe { windows_sys::Win32::System::Memory::VirtualUnlock(ptr as *const c_void, size) };
+}
+
+/// Protections in effect for the memory of a `SecretBox`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Protections {
+    locked: bool,
+    secret_memory: bool,
+    excluded_from_core_dumps: bool,
+    hidden_from_forks: bool,
+    encrypted_at_rest: bool,
+}
+
+impl Protections {
+    /// Returns whether the memory is locked against swapping
+    #[inline]
+    pub fn locked(&self) -> bool {
+        self.locked
+    }
+
+    /// Returns whether the memory was allocated with `memfd_secret` (Linux only)
+    ///
+    /// Such memory is not mapped into the address space of the kernel or of other processes, and
+    /// is never swapped.
+    #[inline]
+    pub fn secret_memory(&self) -> bool {
+        self.secret_memory
+    }
+
+    /// Returns whether the memory is excluded from core dumps, via `MADV_DONTDUMP` (Linux only)
+    #[inline]
+    pub fn excluded_from_core_dumps(&self) -> bool {
+        self.excluded_from_core_dumps
+    }
+
+    /// Returns whether forked child processes cannot read the memory (Linux only)
+    ///
+    /// This uses `MADV_WIPEONFORK`, so that the child sees zeros, or `MADV_DONTFORK` for
+    /// `memfd_secret` memory, which is not mapped into the child at all.
+    #[inline]
+    pub fn hidden_from_forks(&self) -> bool {
+        self.hidden_from_forks
+    }
+
+    /// Returns whether the memory is encrypted while not revealed, via `CryptProtectMemory`
+    /// (Windows with the `windows-protect` feature only)
+    #[inline]
+    pub fn encrypted_at_rest(&self) -> bool {
+        self.encrypted_at_rest
+    }
+}
+
+/// Returns the layout of whole pages for a value of `size` bytes with alignment `align`
+fn page_layout(size: usize, align: usize) -> Layout {
+    let page = page_size().max(align);
+    // round up to whole pages, so that no other data shares the locked pages
+    let size = size.max(1).div_ceil(page) * page;
+    Layout::from_size_align(size, page).expect("secret is too large")
+}
+
+/// Zeroed, page-aligned allocation of whole pages, protected as far as possible
+pub(crate) struct Pages {
+    pub(crate) ptr: NonNull<u8>,
+    layout: Layout,
+    pub(crate) protections: Protections,
+}
+
+impl Pages {
+    /// Allocates room for a value of `size` bytes with alignment `align`, and tries to lock it
+    #[cfg(not(target_os = "linux"))]
+    pub(crate) fn new(size: usize, align: usize) -> (Pages, io::Result<()>) {
+        let layout = page_layout(size, align);
+        let ptr = unsafe { alloc::alloc_zeroed(layout) };
+        let ptr = NonNull::new(ptr).unwrap_or_else(|| alloc::handle_alloc_error(layout));
+
+        let res = lock(ptr.as_ptr(), layout.size());
+        let protections = Protections {
+            locked: res.is_ok(),
+            ..Protections::default()
+        };
+        (
+            Pages {
+                ptr,
+                layout,
+                protections,
+            },
+            res,
+        )
+    }
+
+    /// Allocates room for a value of `size` bytes with alignment `align`, and tries to protect it
+    ///
+    /// `memfd_secret` is preferred, falling back to an anonymous mapping locked with `mlock`.
+    #[cfg(target_os = "linux")]
+    pub(crate) fn new(size: usize, align: usize) -> (Pages, io::Result<()>) {
+        let layout = page_layout(size, align);
+        assert!(
+            layout.align() == page_size(),
+            "alignment of secret exceeds the page size"
+        );
+        let mut protections = Protections::default();
+
+        let (ptr, res) = match linux::map_secret_memory(layout.size()) {
+            Some(ptr) => {
+                // secret memory is locked implicitly
+                protections.secret_memory = true;
+                (ptr, Ok(()))
+            }
+            None => {
+                let ptr = linux::map_anonymous(layout.size())
+                    .unwrap_or_else(|| alloc::handle_alloc_error(layout));
+                (ptr, lock(ptr.as_ptr(), layout.size()))
+            }
+        };
+        protections.locked = res.is_ok();
+
+        let addr = ptr.as_ptr() as *mut c_void;
+        protections.excluded_from_core_dumps =
+            unsafe { libc::madvise(addr, layout.size(), libc::MADV_DONTDUMP) } == 0;
+        // `MADV_WIPEONFORK` is only supported for private mappings
+        let fork_advice = if protections.secret_memory {
+            libc::MADV_DONTFORK
+        } else {
+            libc::MADV_WIPEONFORK
+        };
+        protections.hidden_from_forks =
+            unsafe { libc::madvise(addr, layout.size(), fork_advice) } == 0;
+
+        (
+            Pages {
+                ptr,
+                layout,
+                protections,
+            },
+            res,
+        )
+    }
+
+    /// Returns the size of the allocation, which is rounded up to whole pages
+    #[inline]
+    pub(crate) fn size(&self) -> usize {
+        self.layout.size()
+    }
+
+    /// Overwrites all pages with zeros
+    #[inline]
+    fn wipe(&mut self) {
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.layout.size()) }.zeroize();
+    }
+}
+
+impl Drop for Pages {
+    fn drop(&mut self) {
+        self.wipe();
+
+        // unmapping unlocks as well
+        #[cfg(target_os = "linux")]
+        unsafe {
+            libc::munmap(self.ptr.as_ptr() as *mut c_void, self.layout.size());
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            if self.protections.locked {
+                unlock(self.ptr.as_ptr(), self.layout.size());
+            }
+            unsafe { alloc::dealloc(self.ptr.as_ptr(), self.layout) };
+        }
+    }
+}
+
+#[cfg(all(windows, feature = "windows-protect"))]
+impl Pages {
+    /// Encrypts all pages in place
+    ///
+    /// Whole pages are always a multiple of `CRYPTPROTECTMEMORY_BLOCK_SIZE`, so the length of the
+    /// value is not revealed by the padding either.
+    fn encrypt(&self) {
+        use windows_sys::Win32::Security::Cryptography::{
+            CryptProtectMemory, CRYPTPROTECTMEMORY_SAME_PROCESS,
+        };
+
+        let size = u32::try_from(self.layout.size()).expect("secret is too large to encrypt");
+        let ok = unsafe {
+            CryptProtectMemory(
+                self.ptr.as_ptr() as *mut c_void,
+                size,
+                CRYPTPROTECTMEMORY_SAME_PROCESS,
+            )
+        };
+        // only fails for sizes that are not a multiple of the block size
+        assert!(ok != 0, "CryptProtectMemory failed");
+    }
+
+    /// Decrypts all pages in place
+    fn decrypt(&self) {
+        use windows_sys::Win32::Security::Cryptography::{
+            CryptUnprotectMemory, CRYPTPROTECTMEMORY_SAME_PROCESS,
+        };
+
+        let size = u32::try_from(self.layout.size()).expect("secret is too large to encrypt");
+        let ok = unsafe {
+            CryptUnprotectMemory(
+                self.ptr.as_ptr() as *mut c_void,
+                size,
+                CRYPTPROTECTMEMORY_SAME_PROCESS,
+            )
+        };
+        assert!(ok != 0, "CryptUnprotectMemory failed");
+    }
+}
+
+/// Keeps the pages of a `SecretBox` decrypted, re-encrypting them when dropped
+#[cfg(all(windows, feature = "windows-protect"))]
+struct Decrypted<'a> {
+    pages: &'a Pages,
+    _access: MutexGuard<'a, ()>,
+}
+
+#[cfg(all(windows, feature = "windows-protect"))]
+impl Drop for Decrypted<'_> {
+    fn drop(&mut self) {
+        self.pages.encrypt();
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) mod linux {
+    use std::ptr::{self, NonNull};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// Set once `memfd_secret` turned out to be unsupported by the kernel
+    pub(crate) static MEMFD_SECRET_UNSUPPORTED: AtomicBool = AtomicBool::new(false);
+
+    /// Maps `size` bytes of secret memory, or returns `None` if not possible
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    pub(super) fn map_secret_memory(size: usize) -> Option<NonNull<u8>> {
+        if MEMFD_SECRET_UNSUPPORTED.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        let fd = unsafe { libc::syscall(libc::SYS_memfd_secret, 0) };
+        if fd < 0 {
+            // also returned if secret memory is disabled on the kernel command line
+            if std::io::Error::last_os_error().raw_os_error() == Some(libc::ENOSYS) {
+                MEMFD_SECRET_UNSUPPORTED.store(true, Ordering::Relaxed);
+            }
+            return None;
+        }
+
+        let fd = fd as libc::c_int;
+        let ptr = unsafe {
+            if libc::ftruncate(fd, size as libc::off_t) == 0 {
+                libc::mmap(
+                    ptr::null_mut(),
+                    size,
+                    libc::PROT_READ | libc::PROT_WRITE,
+                    libc::MAP_SHARED,
+                    fd,
+                    0,
+                )
+            } else {
+                libc::MAP_FAILED
+            }
+        };
+        // the mapping keeps the memory alive
+        unsafe { libc::close(fd) };
+
+        if ptr == libc::MAP_FAILED {
+            None
+        } else {
+            NonNull::new(ptr as *mut u8)
+        }
+    }
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    pub(super) fn map_secret_memory(_size: usize) -> Option<NonNull<u8>> {
+        None
+    }
+
+    /// Maps `size` bytes of private anonymous memory
+    pub(super) fn map_anonymous(size: usize) -> Option<NonNull<u8>> {
+        let ptr = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                size,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+                -1,
+                0,
+            )
+        };
+
+        if ptr == libc::MAP_FAILED {
+            None
+        } else {
+            NonNull::new(ptr as *mut u8)
+        }
+    }
+}
+
+/// A heap-allocated secret, kept in memory that is locked against swapping
+///
+/// `Secret` does not keep its value from being written to swap or to core dumps. `SecretBox`
+/// places its value on pages of its own, locks them into memory with `mlock` (`VirtualLock` on
+/// Windows) and wipes them when dropped.
+///
+/// On Linux, the pages are allocated with `memfd_secret` if the kernel supports it, which also
+/// removes them from the kernel's own mapping of memory. They are excluded from core dumps and not
+/// readable by forked child processes. `protections` reports which of these are in effect.
+///
+/// Locking fails if the process exceeds its limit of locked memory. `try_new` reports this as a
+/// `LockError`; `new_best_effort` falls back to unlocked memory, which is still wiped on drop, and
+/// `is_locked` tells which one was used.
+///
+/// On Windows with the `windows-protect` feature, the pages are additionally kept encrypted with
+/// `CryptProtectMemory` while the value is not being accessed. `reveal` and `reveal_mut` decrypt
+/// the value until the guard they return is dropped, `with_revealed` and `with_revealed_mut` for
+/// the duration of the closure.
+///
+/// Only the memory of the value itself is locked. Heap allocations owned by the value, such as the
+/// buffer of a `String`, are not; use `SecretBox<[u8]>` for dynamically sized data.
+///
+/// ```rust
+/// use sec::SecretBox;
+///
+/// let key = SecretBox::from_vec_best_effort(vec![0x42; 32]);
+/// assert_eq!(key.with_revealed(|key| key.len()), 32);
+/// ```
+pub struct SecretBox<T: ?Sized> {
+    ptr: NonNull<T>,
+    pages: Pages,
+    // held while the pages are decrypted
+    #[cfg(all(windows, feature = "windows-protect"))]
+    access: Mutex<()>,
+    _marker: PhantomData<T>,
+}
+
+unsafe impl<T: Send + ?Sized> Send for SecretBox<T> {}
+unsafe impl<T: Sync + ?Sized> Sync for SecretBox<T> {}
+
+impl<T> SecretBox<T> {
+    /// Moves `value` into locked memory, failing if the memory cannot be locked
+    ///
+    /// The memory is allocated and locked before `value` is moved into it.
+    pub fn try_new(value: T) -> Result<SecretBox<T>, LockError> {
+        let (pages, res) = Pages::new(mem::size_of::<T>(), mem::align_of::<T>());
+        res.map_err(LockError)?;
+        Ok(SecretBox::with_pages(pages, value))
+    }
+
+    /// Moves `value` into locked memory, falling back to unlocked memory if locking fails
+    pub fn new_best_effort(value: T) -> SecretBox<T> {
+        let (pages, _) = Pages::new(mem::size_of::<T>(), mem::align_of::<T>());
+        SecretBox::with_pages(pages, value)
+    }
+
+    fn with_pages(pages: Pages, value: T) -> SecretBox<T> {
+        let ptr = pages.ptr.cast::<T>();
+        unsafe { ptr::write(ptr.as_ptr(), value) };
+        SecretBox::from_parts(ptr, pages)
+    }
+}
+
+impl SecretBox<[u8]> {
+    /// Copies `bytes` into locked memory and wipes `bytes`, failing if the memory cannot be locked
+    ///
+    /// `bytes` is wiped even if locking fails.
+    pub fn try_from_vec(bytes: Vec<u8>) -> Result<SecretBox<[u8]>, LockError> {
+        let (pages, res) = Pages::new(bytes.len(), 1);
+        if let Err(err) = res {
+            let mut bytes = bytes;
+            bytes.zeroize();
+            return Err(LockError(err));
+        }
+        Ok(SecretBox::with_bytes(pages, bytes))
+    }
+
+    /// Copies `bytes` into locked memory and wipes `bytes`, falling back to unlocked memory if
+    /// locking fails
+    pub fn from_vec_best_effort(bytes: Vec<u8>) -> SecretBox<[u8]> {
+        let (pages, _) = Pages::new(bytes.len(), 1);
+        SecretBox::with_bytes(pages, bytes)
+    }
+
+    fn with_bytes(pages: Pages, mut bytes: Vec<u8>) -> SecretBox<[u8]> {
+        unsafe { ptr::copy_nonoverlapping(bytes.as_ptr(), pages.ptr.as_ptr(), bytes.len()) };
+        let ptr = NonNull::slice_from_raw_parts(pages.ptr, bytes.len());
+        bytes.zeroize();
+        SecretBox::from_parts(ptr, pages)
+    }
+}
+
+impl<T: ?Sized> SecretBox<T> {
+    /// Takes ownership of the value at `ptr`, which is held in `pages`
+    fn from_parts(ptr: NonNull<T>, pages: Pages) -> SecretBox<T> {
+        #[cfg(all(windows, feature = "windows-protect"))]
+        let pages = {
+            let mut pages = pages;
+            pages.encrypt();
+            pages.protections.encrypted_at_rest = true;
+            pages
+        };
+
+        SecretBox {
+            ptr,
+            pages,
+            #[cfg(all(windows, feature = "windows-protect"))]
+            access: Mutex::new(()),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Decrypts the pages until the returned guard is dropped
+    #[cfg(all(windows, feature = "windows-protect"))]
+    fn decrypt(&self) -> Decrypted<'_> {
+        let access = self.access.lock().unwrap_or_else(PoisonError::into_inner);
+        self.pages.decrypt();
+        Decrypted {
+            pages: &self.pages,
+            _access: access,
+        }
+    }
+
+    /// Returns whether the memory holding the value is locked against swapping
+    #[inline]
+    pub fn is_locked(&self) -> bool {
+        self.pages.protections.locked
+    }
+
+    /// Returns the protections in effect for the memory holding the value
+    ///
+    /// Services that rely on them can check this at startup, and refuse to run without.
+    #[inline]
+    pub fn protections(&self) -> Protections {
+        self.pages.protections
+    }
+
+    /// **Reveals** the held value, until the returned guard is dropped
+    ///
+    /// With the `windows-protect` feature, the value is decrypted here and encrypted again when
+    /// the guard is dropped. Revealing the same `SecretBox` again while a guard is alive deadlocks.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "reveal only in approved modules, see `RevealToken`")
+    )]
+    pub fn reveal(&self) -> SecretBoxRef<'_, T> {
+        audit::revealed::<T>();
+        SecretBoxRef {
+            #[cfg(all(windows, feature = "windows-protect"))]
+            _decrypted: self.decrypt(),
+            value: unsafe { self.ptr.as_ref() },
+        }
+    }
+
+    /// **Reveals** the held value mutably, until the returned guard is dropped
+    ///
+    /// See `reveal`.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "reveal only in approved modules, see `RevealToken`")
+    )]
+    pub fn reveal_mut(&mut self) -> SecretBoxMut<'_, T> {
+        audit::revealed::<T>();
+        let mut ptr = self.ptr;
+        SecretBoxMut {
+            #[cfg(all(windows, feature = "windows-protect"))]
+            _decrypted: self.decrypt(),
+            value: unsafe { ptr.as_mut() },
+        }
+    }
+
+    /// **Reveals** the held value to `f`, returning its result
+    ///
+    /// With the `windows-protect` feature, the value is decrypted before and encrypted again after
+    /// calling `f`. Calling `with_revealed` on the same `SecretBox` from within `f` deadlocks.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "reveal only in approved modules, see `RevealToken`")
+    )]
+    pub fn with_revealed<R, F: FnOnce(&T) -> R>(&self, f: F) -> R {
+        audit::revealed::<T>();
+        #[cfg(all(windows, feature = "windows-protect"))]
+        let _decrypted = self.decrypt();
+        f(unsafe { self.ptr.as_ref() })
+    }
+
+    /// **Reveals** the held value mutably to `f`, returning its result
+    ///
+    /// See `with_revealed`.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "reveal only in approved modules, see `RevealToken`")
+    )]
+    pub fn with_revealed_mut<R, F: FnOnce(&mut T) -> R>(&mut self, f: F) -> R {
+        audit::revealed::<T>();
+        let mut ptr = self.ptr;
+        #[cfg(all(windows, feature = "windows-protect"))]
+        let _decrypted = self.decrypt();
+        f(unsafe { ptr.as_mut() })
+    }
+}
+
+/// Wipes the held bytes, leaving zeros of the same length
+impl Zeroize for SecretBox<[u8]> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.pages.wipe();
+        #[cfg(all(windows, feature = "windows-protect"))]
+        self.pages.encrypt();
+    }
+}
+
+impl<T: ?Sized> Drop for SecretBox<T> {
+    fn drop(&mut self) {
+        #[cfg(all(windows, feature = "windows-protect"))]
+        self.pages.decrypt();
+        // the pages are wiped and freed once `self.pages` is dropped
+        unsafe { ptr::drop_in_place(self.ptr.as_ptr()) };
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for SecretBox<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SecretBox(...)")
+    }
+}
+
+/// The revealed value of a `SecretBox`, returned by `SecretBox::reveal`
+///
+/// With the `windows-protect` feature, the value is encrypted again when the guard is dropped.
+pub struct SecretBoxRef<'a, T: ?Sized> {
+    value: &'a T,
+    #[cfg(all(windows, feature = "windows-protect"))]
+    _decrypted: Decrypted<'a>,
+}
+
+impl<T: ?Sized> Deref for SecretBoxRef<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for SecretBoxRef<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SecretBoxRef(...)")
+    }
+}
+
+/// The mutably revealed value of a `SecretBox`, returned by `SecretBox::reveal_mut`
+///
+/// See `SecretBoxRef`.
+pub struct SecretBoxMut<'a, T: ?Sized> {
+    value: &'a mut T,
+    #[cfg(all(windows, feature = "windows-protect"))]
+    _decrypted: Decrypted<'a>,
+}
+
+impl<T: ?Sized> Deref for SecretBoxMut<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<T: ?Sized> DerefMut for SecretBoxMut<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        self.value
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for SecretBoxMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SecretBoxMut(...)")
+    }
+}
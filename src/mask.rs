@@ -0,0 +1,133 @@
+use alloc::string::String;
+
+use super::{audit, Secret};
+
+/// Replacement for the masked portion, its length is independent of the length of the secret
+const MASK: &str = "****************";
+
+/// Masks all but the first `keep_first` and last `keep_last` characters of `s`
+///
+/// If `s` does not have more characters than are kept, it is masked completely.
+fn mask(s: &str, keep_first: usize, keep_last: usize) -> String {
+    let len = s.chars().count();
+    if len <= keep_first.saturating_add(keep_last) {
+        return String::from(MASK);
+    }
+
+    // both indices are char boundaries, since they are taken from `char_indices`
+    let head_end = s.char_indices().nth(keep_first).map_or(s.len(), |(i, _)| i);
+    let tail_start = s
+        .char_indices()
+        .nth(len - keep_last)
+        .map_or(s.len(), |(i, _)| i);
+
+    let mut out = String::with_capacity(head_end + MASK.len() + (s.len() - tail_start));
+    out.push_str(&s[..head_end]);
+    out.push_str(MASK);
+    out.push_str(&s[tail_start..]);
+    out
+}
+
+/// Splits `s` after `prefix_len` characters, keeping everything if `s` is not longer than that
+fn split_prefix<P>(s: &str, prefix_len: usize) -> (String, Secret<&str, P>) {
+    match s.char_indices().nth(prefix_len) {
+        Some((i, _)) => (String::from(&s[..i]), Secret::wrap(&s[i..])),
+        None => (String::new(), Secret::wrap(s)),
+    }
+}
+
+/// Splits off the longest of `prefixes` that `s` starts with
+fn split_prefix_matching<'a, P>(
+    s: &'a str,
+    prefixes: &[&str],
+) -> Option<(String, Secret<&'a str, P>)> {
+    prefixes
+        .iter()
+        .filter(|prefix| !prefix.is_empty() && s.starts_with(**prefix))
+        .max_by_key(|prefix| prefix.len())
+        .map(|prefix| (String::from(*prefix), Secret::wrap(&s[prefix.len()..])))
+}
+
+impl<P> Secret<String, P> {
+    /// Partially **reveals** the held value, showing only its last `keep_last` characters
+    ///
+    /// The masked portion is always replaced by the same number of `*`, so the result does not
+    /// reveal the length of the secret. If the secret is not longer than `keep_last` characters,
+    /// a fully masked string is returned instead.
+    ///
+    /// Only use this where policy allows showing parts of a secret, e.g. "key ending in a1b2".
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn masked(&self, keep_last: usize) -> String {
+        audit::revealed::<String>();
+        mask(&self.1, 0, keep_last)
+    }
+
+    /// Partially **reveals** the held value, showing its first `keep_first` and last `keep_last`
+    /// characters
+    ///
+    /// See `masked`.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn masked_ends(&self, keep_first: usize, keep_last: usize) -> String {
+        audit::revealed::<String>();
+        mask(&self.1, keep_first, keep_last)
+    }
+
+    /// Splits off a public prefix of `prefix_len` characters, keeping the remainder secret
+    ///
+    /// Many tokens carry an identifying prefix (e.g. `ghp_` or `sk_live_`), which can be logged
+    /// safely. If the secret is not longer than `prefix_len` characters, nothing is split off and
+    /// the returned prefix is empty.
+    #[inline]
+    pub fn split_prefix(&self, prefix_len: usize) -> (String, Secret<&str, P>) {
+        split_prefix(&self.1, prefix_len)
+    }
+
+    /// Splits off the longest of `prefixes` the secret starts with, keeping the remainder secret
+    ///
+    /// Returns `None` if no prefix matches.
+    #[inline]
+    pub fn split_prefix_matching(&self, prefixes: &[&str]) -> Option<(String, Secret<&str, P>)> {
+        split_prefix_matching(&self.1, prefixes)
+    }
+}
+
+impl<'a, P> Secret<&'a str, P> {
+    /// Partially **reveals** the held value, showing only its last `keep_last` characters
+    ///
+    /// See `Secret<String>::masked`.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn masked(&self, keep_last: usize) -> String {
+        audit::revealed::<&str>();
+        mask(self.1, 0, keep_last)
+    }
+
+    /// Partially **reveals** the held value, showing its first `keep_first` and last `keep_last`
+    /// characters
+    ///
+    /// See `Secret<String>::masked`.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    pub fn masked_ends(&self, keep_first: usize, keep_last: usize) -> String {
+        audit::revealed::<&str>();
+        mask(self.1, keep_first, keep_last)
+    }
+
+    /// Splits off a public prefix of `prefix_len` characters, keeping the remainder secret
+    ///
+    /// See `Secret<String>::split_prefix`.
+    #[inline]
+    pub fn split_prefix(&self, prefix_len: usize) -> (String, Secret<&'a str, P>) {
+        split_prefix(self.1, prefix_len)
+    }
+
+    /// Splits off the longest of `prefixes` the secret starts with, keeping the remainder secret
+    ///
+    /// See `Secret<String>::split_prefix_matching`.
+    #[inline]
+    pub fn split_prefix_matching(&self, prefixes: &[&str]) -> Option<(String, Secret<&'a str, P>)> {
+        split_prefix_matching(self.1, prefixes)
+    }
+}
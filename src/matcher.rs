@@ -0,0 +1,168 @@
+//! Multi-pattern matching for scrubbing secrets from text
+
+use std::vec::Vec;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use super::scrub::REDACTED;
+
+/// Node of the trie underlying an Aho-Corasick automaton
+#[derive(Default)]
+struct State {
+    // transitions, as bytes sorted ascending and their target states
+    bytes: Vec<u8>,
+    targets: Vec<u32>,
+    // state to continue with if there is no transition for the next byte
+    fail: u32,
+    // length of the longest pattern ending in this state, zero if none
+    len: usize,
+}
+
+impl State {
+    #[inline]
+    fn next(&self, byte: u8) -> Option<u32> {
+        self.bytes
+            .binary_search(&byte)
+            .ok()
+            .map(|index| self.targets[index])
+    }
+}
+
+/// Aho-Corasick automaton finding all occurrences of a set of patterns in a single pass
+pub(crate) struct Matcher {
+    states: Vec<State>,
+}
+
+impl Matcher {
+    /// Builds an automaton for `patterns`; empty patterns never match
+    pub(crate) fn new<'a, I: IntoIterator<Item = &'a [u8]>>(patterns: I) -> Matcher {
+        let mut states = vec![State::default()];
+
+        for pattern in patterns {
+            if pattern.is_empty() {
+                continue;
+            }
+
+            let mut current = 0;
+            for &byte in pattern {
+                current = match states[current].next(byte) {
+                    Some(next) => next as usize,
+                    None => {
+                        let next = states.len();
+                        states.push(State::default());
+                        let state = &mut states[current];
+                        let index = state.bytes.binary_search(&byte).unwrap_err();
+                        state.bytes.insert(index, byte);
+                        state.targets.insert(index, next as u32);
+                        next
+                    }
+                };
+            }
+            states[current].len = states[current].len.max(pattern.len());
+        }
+
+        // compute failure links breadth-first, so those of shorter prefixes are known
+        let mut queue = states[0].targets.clone();
+        let mut head = 0;
+        while head < queue.len() {
+            let current = queue[head] as usize;
+            head += 1;
+
+            for index in 0..states[current].bytes.len() {
+                let byte = states[current].bytes[index];
+                let child = states[current].targets[index] as usize;
+
+                // the longest proper suffix of the child's prefix that is in the trie as well
+                let mut suffix = states[current].fail as usize;
+                let fail = loop {
+                    if let Some(next) = states[suffix].next(byte) {
+                        break next;
+                    }
+                    if suffix == 0 {
+                        break 0;
+                    }
+                    suffix = states[suffix].fail as usize;
+                };
+
+                states[child].fail = fail;
+                states[child].len = states[child].len.max(states[fail as usize].len);
+                queue.push(child as u32);
+            }
+        }
+
+        Matcher { states }
+    }
+
+    /// Returns the byte ranges covered by occurrences of any pattern, merged where they overlap
+    ///
+    /// Overlapping occurrences of different patterns are merged into a single range, so that no
+    /// part of either is left over once the range is replaced.
+    fn ranges(&self, input: &[u8]) -> Vec<(usize, usize)> {
+        let mut ranges: Vec<(usize, usize)> = Vec::new();
+        let mut current = 0;
+
+        for (pos, &byte) in input.iter().enumerate() {
+            current = loop {
+                if let Some(next) = self.states[current].next(byte) {
+                    break next as usize;
+                }
+                if current == 0 {
+                    break 0;
+                }
+                current = self.states[current].fail as usize;
+            };
+
+            let len = self.states[current].len;
+            if len == 0 {
+                continue;
+            }
+
+            let mut start = pos + 1 - len;
+            while let Some(&(prev_start, prev_end)) = ranges.last() {
+                if start >= prev_end {
+                    break;
+                }
+                start = start.min(prev_start);
+                ranges.pop();
+            }
+            ranges.push((start, pos + 1));
+        }
+
+        ranges
+    }
+
+    /// Replaces all occurrences of any pattern in `buf` with `[REDACTED]`
+    ///
+    /// Returns whether anything was replaced; `buf` is left untouched otherwise.
+    pub(crate) fn scrub_bytes(&self, buf: &mut Vec<u8>) -> bool {
+        let ranges = self.ranges(buf);
+        if ranges.is_empty() {
+            return false;
+        }
+
+        let mut out = Vec::with_capacity(buf.len());
+        let mut pos = 0;
+        for (start, end) in ranges {
+            out.extend_from_slice(&buf[pos..start]);
+            out.extend_from_slice(REDACTED);
+            pos = end;
+        }
+        out.extend_from_slice(&buf[pos..]);
+
+        #[cfg(feature = "zeroize")]
+        buf.zeroize();
+        *buf = out;
+        true
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Matcher {
+    fn drop(&mut self) {
+        // the transitions spell out the patterns
+        for state in &mut self.states {
+            state.bytes.zeroize();
+        }
+    }
+}
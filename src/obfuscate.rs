@@ -0,0 +1,160 @@
+//! Support code for `secret!` and `secret_bytes!`
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::hint::black_box;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+use super::Secret;
+
+include!(concat!(env!("OUT_DIR"), "/obfuscation_key.rs"));
+
+/// Creates a `Secret<String>` from a string literal that is obfuscated in the binary
+///
+/// The literal is XOR-encoded at compile time with a key that is generated anew for every build of
+/// this crate, and only decoded at runtime, so it does not show up in the output of `strings`.
+///
+/// ```rust
+/// use sec::secret;
+///
+/// let password = secret!("THIS-SHOULD-BE-SECRET");
+/// assert_eq!(password.reveal(), "THIS-SHOULD-BE-SECRET");
+/// ```
+///
+/// **IMPORTANT**: This is obfuscation, not encryption. The key is part of the binary as well, so
+/// anyone with access to the binary can recover the value with little effort.
+#[macro_export]
+macro_rules! secret {
+    ($lit:literal) => {{
+        const SEED: u64 = $crate::obfuscate::seed(file!(), line!(), column!());
+        const ENCODED: [u8; $lit.len()] = $crate::obfuscate::encode($lit.as_bytes(), SEED);
+        $crate::obfuscate::decode_string(&ENCODED, SEED)
+    }};
+}
+
+/// Creates a `Secret<&'static [u8]>` from a byte string literal that is obfuscated in the binary
+///
+/// The literal is decoded on first use, into a buffer that is kept for the rest of the program
+/// and shared by all later uses of the same invocation:
+///
+/// ```rust
+/// use sec::secret_bytes;
+///
+/// let key = secret_bytes!(b"THIS-SHOULD-BE-SECRET");
+/// assert_eq!(key.reveal(), b"THIS-SHOULD-BE-SECRET");
+/// ```
+///
+/// See `secret!`.
+#[macro_export]
+macro_rules! secret_bytes {
+    ($lit:literal) => {{
+        const SEED: u64 = $crate::obfuscate::seed(file!(), line!(), column!());
+        const LEN: usize = $lit.len();
+        const ENCODED: [u8; LEN] = $crate::obfuscate::encode($lit, SEED);
+        static DECODED: $crate::obfuscate::Decoded<LEN> = $crate::obfuscate::Decoded::new();
+        DECODED.get(&ENCODED, SEED)
+    }};
+}
+
+/// Derives the seed of the keystream for a literal from the build key and its location
+#[doc(hidden)]
+pub const fn seed(file: &str, line: u32, column: u32) -> u64 {
+    // FNV-1a, so that literals at the same position in different files get different keystreams
+    let file = file.as_bytes();
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    let mut i = 0;
+    while i < file.len() {
+        hash = (hash ^ file[i] as u64).wrapping_mul(0x0100_0000_01b3);
+        i += 1;
+    }
+
+    KEY ^ hash ^ (((line as u64) << 32) | column as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15)
+}
+
+/// Returns the next state and keystream byte (SplitMix64)
+const fn next(state: u64) -> (u64, u8) {
+    let state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    (state, (z ^ (z >> 31)) as u8)
+}
+
+#[doc(hidden)]
+pub const fn encode<const N: usize>(data: &[u8], seed: u64) -> [u8; N] {
+    let mut out = [0; N];
+    let mut state = seed;
+    let mut i = 0;
+    while i < N {
+        let (s, k) = next(state);
+        state = s;
+        out[i] = data[i] ^ k;
+        i += 1;
+    }
+    out
+}
+
+#[doc(hidden)]
+pub fn decode_bytes(data: &[u8], seed: u64) -> Secret<Vec<u8>> {
+    // `black_box` keeps the optimizer from decoding the literal at compile time
+    let data = black_box(data);
+    let mut state = black_box(seed);
+
+    Secret::wrap(
+        data.iter()
+            .map(|b| {
+                let (s, k) = next(state);
+                state = s;
+                b ^ k
+            })
+            .collect(),
+    )
+}
+
+/// Decoded value of a single `secret_bytes!` invocation
+#[doc(hidden)]
+pub struct Decoded<const N: usize>(AtomicPtr<[u8; N]>);
+
+impl<const N: usize> Decoded<N> {
+    #[doc(hidden)]
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Decoded<N> {
+        Decoded(AtomicPtr::new(ptr::null_mut()))
+    }
+
+    #[doc(hidden)]
+    pub fn get(&'static self, data: &[u8; N], seed: u64) -> Secret<&'static [u8]> {
+        let mut decoded = self.0.load(Ordering::Acquire);
+        if decoded.is_null() {
+            let mut buf = Box::new([0; N]);
+            buf.copy_from_slice(&decode_bytes(data, seed).1);
+            let buf = Box::into_raw(buf);
+            decoded = match self.0.compare_exchange(
+                ptr::null_mut(),
+                buf,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => buf,
+                Err(current) => {
+                    // SAFETY: `buf` was just created by `Box::into_raw` and lost the race, so it
+                    // was never shared
+                    drop(unsafe { Box::from_raw(buf) });
+                    current
+                }
+            };
+        }
+
+        // SAFETY: a non-null pointer was stored from `Box::into_raw` exactly once and is never
+        // freed, so it is valid for `'static`
+        Secret::wrap(unsafe { &*decoded })
+    }
+}
+
+#[doc(hidden)]
+pub fn decode_string(data: &[u8], seed: u64) -> Secret<String> {
+    decode_bytes(data, seed)
+        .map_revealed(|bytes| String::from_utf8(bytes).expect("obfuscated literal is valid UTF-8"))
+}
@@ -0,0 +1,97 @@
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use super::{audit, Secret};
+
+/// A secret that can be revealed only once
+///
+/// Useful for one-shot values such as freshly generated recovery codes or passwords that are
+/// hashed right away. `reveal_once` consumes the `SecretOnce`, while `try_reveal` can be called
+/// through a shared reference, e.g. from multiple threads through an `Arc`, and hands out the
+/// value at most once.
+pub struct SecretOnce<T> {
+    revealed: AtomicBool,
+    value: UnsafeCell<Option<T>>,
+}
+
+// SAFETY: the value is only accessed through a shared reference by the single caller of
+// `try_reveal` that flipped `revealed`, which moves it out. This requires `T: Send`, but not
+// `T: Sync`, as no references to the value are ever shared.
+unsafe impl<T: Send> Sync for SecretOnce<T> {}
+
+impl<T> SecretOnce<T> {
+    /// Creates a new single-use secret
+    #[inline]
+    pub const fn new(val: T) -> SecretOnce<T> {
+        SecretOnce {
+            revealed: AtomicBool::new(false),
+            value: UnsafeCell::new(Some(val)),
+        }
+    }
+
+    /// **Reveals** the held value by unwrapping
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value has already been revealed through `try_reveal`.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `into_secret` and `reveal_into_with`")
+    )]
+    pub fn reveal_once(self) -> T {
+        audit::revealed::<T>();
+        self.value
+            .into_inner()
+            .expect("secret has already been revealed")
+    }
+
+    /// **Reveals** the held value by moving it out, if it has not been revealed before
+    ///
+    /// Only the first call returns the value, all subsequent calls return `None`, even if they
+    /// happen concurrently.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "reveal only in approved modules, see `RevealToken`")
+    )]
+    pub fn try_reveal(&self) -> Option<T> {
+        if self.revealed.swap(true, Ordering::AcqRel) {
+            return None;
+        }
+
+        audit::revealed::<T>();
+        // SAFETY: only the caller that flipped `revealed` gets here, and the value is not accessed
+        // through a shared reference anywhere else
+        unsafe { (*self.value.get()).take() }
+    }
+
+    /// Returns whether the held value has been revealed through `try_reveal`
+    #[inline]
+    pub fn is_revealed(&self) -> bool {
+        self.revealed.load(Ordering::Acquire)
+    }
+
+    /// Converts into a regular, reusable `Secret`, if the value has not been revealed yet
+    #[inline]
+    pub fn into_secret(self) -> Option<Secret<T>> {
+        self.value.into_inner().map(Secret::wrap)
+    }
+}
+
+impl<T> From<Secret<T>> for SecretOnce<T> {
+    #[inline]
+    fn from(secret: Secret<T>) -> SecretOnce<T> {
+        SecretOnce::new(secret.1)
+    }
+}
+
+impl<T> fmt::Debug for SecretOnce<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "...")
+    }
+}
@@ -0,0 +1,118 @@
+//! Arithmetic and bitwise operators, enabled by the `ops` feature
+//!
+//! Operators are applied to the held values and the result is wrapped in a `Secret` again, so
+//! secret numbers can be combined without revealing them. Overflow behaves like for the held type.
+
+use core::iter::{Product, Sum};
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Mul, MulAssign,
+    Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+
+use super::Secret;
+
+macro_rules! impl_secret_ops {
+    ($($op:ident $method:ident $assign:ident $assign_method:ident),*) => {
+        $(
+            impl<T: $op<U>, U, P> $op<Secret<U, P>> for Secret<T, P> {
+                type Output = Secret<T::Output, P>;
+
+                #[inline]
+                fn $method(self, rhs: Secret<U, P>) -> Secret<T::Output, P> {
+                    Secret::wrap(self.1.$method(rhs.1))
+                }
+            }
+
+            impl<T: $assign<U>, U, P> $assign<Secret<U, P>> for Secret<T, P> {
+                #[inline]
+                fn $assign_method(&mut self, rhs: Secret<U, P>) {
+                    self.1.$assign_method(rhs.1)
+                }
+            }
+        )*
+    };
+}
+
+impl_secret_ops!(
+    Add add AddAssign add_assign,
+    Sub sub SubAssign sub_assign,
+    Mul mul MulAssign mul_assign,
+    BitXor bitxor BitXorAssign bitxor_assign,
+    BitAnd bitand BitAndAssign bitand_assign,
+    BitOr bitor BitOrAssign bitor_assign,
+    Shl shl ShlAssign shl_assign,
+    Shr shr ShrAssign shr_assign
+);
+
+// A generic `impl<T: Add<U>, U> Add<U> for Secret<T>` would overlap with the implementation above,
+// so operators with a plain right-hand side are implemented for the primitive integers only.
+
+macro_rules! impl_plain_ops {
+    ($($ty:ty),*) => {
+        $(
+            impl_plain_ops!(
+                @rhs $ty, $ty:
+                Add add AddAssign add_assign,
+                Sub sub SubAssign sub_assign,
+                Mul mul MulAssign mul_assign,
+                BitXor bitxor BitXorAssign bitxor_assign,
+                BitAnd bitand BitAndAssign bitand_assign,
+                BitOr bitor BitOrAssign bitor_assign
+            );
+            impl_plain_ops!(
+                @rhs $ty, u32:
+                Shl shl ShlAssign shl_assign,
+                Shr shr ShrAssign shr_assign
+            );
+        )*
+    };
+    (@rhs $ty:ty, $rhs:ty: $($op:ident $method:ident $assign:ident $assign_method:ident),*) => {
+        $(
+            impl<P> $op<$rhs> for Secret<$ty, P> {
+                type Output = Secret<$ty, P>;
+
+                #[inline]
+                fn $method(self, rhs: $rhs) -> Secret<$ty, P> {
+                    Secret::wrap(self.1.$method(rhs))
+                }
+            }
+
+            impl<P> $assign<$rhs> for Secret<$ty, P> {
+                #[inline]
+                fn $assign_method(&mut self, rhs: $rhs) {
+                    self.1.$assign_method(rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_plain_ops!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<T: Sum<T>, P> Sum<Secret<T, P>> for Secret<T, P> {
+    #[inline]
+    fn sum<I: Iterator<Item = Secret<T, P>>>(iter: I) -> Secret<T, P> {
+        Secret::wrap(iter.map(|secret| secret.1).sum())
+    }
+}
+
+impl<'a, T: Sum<&'a T> + 'a, P> Sum<&'a Secret<T, P>> for Secret<T, P> {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Secret<T, P>>>(iter: I) -> Secret<T, P> {
+        Secret::wrap(iter.map(|secret| &secret.1).sum())
+    }
+}
+
+impl<T: Product<T>, P> Product<Secret<T, P>> for Secret<T, P> {
+    #[inline]
+    fn product<I: Iterator<Item = Secret<T, P>>>(iter: I) -> Secret<T, P> {
+        Secret::wrap(iter.map(|secret| secret.1).product())
+    }
+}
+
+impl<'a, T: Product<&'a T> + 'a, P> Product<&'a Secret<T, P>> for Secret<T, P> {
+    #[inline]
+    fn product<I: Iterator<Item = &'a Secret<T, P>>>(iter: I) -> Secret<T, P> {
+        Secret::wrap(iter.map(|secret| &secret.1).product())
+    }
+}
@@ -0,0 +1,67 @@
+use core::ops::Deref;
+
+use super::{audit, Secret};
+
+mod private {
+    use super::Secret;
+
+    pub trait Sealed {}
+
+    impl<T, P> Sealed for Option<Secret<T, P>> {}
+}
+
+/// Extension trait for optional secrets, such as `Option<Secret<String>>` fields in configuration
+///
+/// ```rust
+/// use sec::prelude::*;
+///
+/// let token: Option<Secret<String>> = Some(Secret::new("THIS-SHOULD-BE-SECRET".to_owned()));
+///
+/// let header: Option<Secret<&str>> = token.as_deref_secret();
+/// assert_eq!(token.reveal_opt().map(String::as_str), Some("THIS-SHOULD-BE-SECRET"));
+/// ```
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait OptionSecretExt<T, P>: private::Sealed {
+    /// **Reveals** the held value, if any, by returning a reference to it
+    fn reveal_opt(&self) -> Option<&T>;
+
+    /// Dereferences the held value, if any, wrapping the result in a secret
+    fn as_deref_secret(&self) -> Option<Secret<&T::Target, P>>
+    where
+        T: Deref;
+
+    /// Applies a function to the held value, if any, wrapping the result in a secret
+    fn map_secret<U, F: FnOnce(T) -> U>(self, f: F) -> Option<Secret<U, P>>;
+
+    /// Returns the held secret, or a secret holding `default` if there is none
+    fn unwrap_or_secret(self, default: T) -> Secret<T, P>;
+}
+
+impl<T, P> OptionSecretExt<T, P> for Option<Secret<T, P>> {
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn reveal_opt(&self) -> Option<&T> {
+        let secret = self.as_ref()?;
+        audit::revealed::<T>();
+        Some(&secret.1)
+    }
+
+    #[inline]
+    fn as_deref_secret(&self) -> Option<Secret<&T::Target, P>>
+    where
+        T: Deref,
+    {
+        self.as_ref().map(Secret::as_deref)
+    }
+
+    #[inline]
+    fn map_secret<U, F: FnOnce(T) -> U>(self, f: F) -> Option<Secret<U, P>> {
+        self.map(|secret| secret.map_revealed(f))
+    }
+
+    #[inline]
+    fn unwrap_or_secret(self, default: T) -> Secret<T, P> {
+        self.unwrap_or_else(|| Secret::wrap(default))
+    }
+}
@@ -0,0 +1,46 @@
+//! Helpers for secret paths and OS strings
+
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+use super::{audit, Secret};
+
+impl<P> Secret<PathBuf, P> {
+    /// Returns a `Path` reference, wrapped in a secret
+    #[inline]
+    pub fn as_path(&self) -> Secret<&Path, P> {
+        Secret::wrap(self.1.as_path())
+    }
+
+    /// Returns and **reveals** a `Path` reference
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `reveal_with` and a `RevealToken`")
+    )]
+    pub fn reveal_path(&self) -> &Path {
+        audit::revealed::<PathBuf>();
+        self.1.as_path()
+    }
+}
+
+impl<P> Secret<OsString, P> {
+    /// Returns an `OsStr` reference, wrapped in a secret
+    #[inline]
+    pub fn as_os_str(&self) -> Secret<&OsStr, P> {
+        Secret::wrap(self.1.as_os_str())
+    }
+
+    /// Returns and **reveals** an `OsStr` reference
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `reveal_with` and a `RevealToken`")
+    )]
+    pub fn reveal_os_str(&self) -> &OsStr {
+        audit::revealed::<OsString>();
+        self.1.as_os_str()
+    }
+}
@@ -0,0 +1,108 @@
+//! Scrubbing secrets from panic messages
+
+use std::any::Any;
+use std::borrow::Cow;
+use std::boxed::Box;
+use std::cell::Cell;
+use std::io::{self, Write};
+use std::panic;
+use std::string::String;
+use std::thread;
+
+use super::registry;
+
+std::thread_local! {
+    /// Set on the threads that pass scrubbed panics on to the previous hook
+    static FORWARDING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Installs a panic hook that removes registered secrets from panic messages
+///
+/// Panics are a classic way for secrets to leak: an `unwrap` on a `Result` whose error embeds a
+/// token prints the token to stderr, and into crash reports. The hook renders the message and
+/// location of every panic and replaces any secret registered with `registry::register` by
+/// `[REDACTED]`.
+///
+/// The previously installed hook is kept, and all panics are passed on to it. Panics whose
+/// message contains no registered secret are passed on unchanged. As the standard library offers
+/// no way to construct a `PanicInfo`, a panic that does contain a secret is passed on by raising
+/// a new panic with the scrubbed message on a short-lived thread of the same name. The previous
+/// hook then reports the location and backtrace of that thread, so the original location is
+/// appended to the message.
+///
+/// Hooks installed after this one, with `panic::set_hook`, run first and receive the unscrubbed
+/// `PanicInfo`; this hook only sees panics they pass on. It should therefore be the last hook
+/// installed, e.g. after those of error reporting libraries.
+///
+/// The hook never panics itself. If the registry cannot be accessed, e.g. because the panic
+/// happened while registering a secret, the message is withheld. If no thread can be spawned, the
+/// scrubbed message is written to stderr instead of being passed on.
+pub fn install_scrubbing_panic_hook() {
+    let previous = panic::take_hook();
+
+    panic::set_hook(Box::new(move |info| {
+        if FORWARDING.with(Cell::get) {
+            return previous(info);
+        }
+
+        let name = thread::current().name().map(String::from);
+        let message = match info.location() {
+            Some(location) => format!(
+                "{}\n(panicked at {})",
+                payload_message(info.payload()),
+                location
+            ),
+            None => String::from(payload_message(info.payload())),
+        };
+
+        // the outer `None` if the registry cannot be accessed
+        let scrubbed_name = match &name {
+            Some(name) => registry::try_scrub_str(name).map(Some),
+            None => Some(None),
+        };
+        match (scrubbed_name, registry::try_scrub_str(&message)) {
+            (Some(None | Some(Cow::Borrowed(_))), Some(Cow::Borrowed(_))) => previous(info),
+            (Some(name), Some(message)) => forward(name.map(Cow::into_owned), message.into_owned()),
+            _ => forward(
+                None,
+                String::from(
+                    "a thread panicked, the message was withheld as it could not be scrubbed",
+                ),
+            ),
+        }
+    }));
+}
+
+/// Raises a panic with `message` on a new thread, for the previous hook to report it
+fn forward(name: Option<String>, message: String) {
+    let mut builder = thread::Builder::new();
+    if let Some(name) = name {
+        builder = builder.name(name);
+    }
+
+    let payload = message.clone();
+    let spawned = builder.spawn(move || {
+        FORWARDING.with(|forwarding| forwarding.set(true));
+        panic::panic_any(payload)
+    });
+    match spawned {
+        Ok(handle) => {
+            let _ = handle.join();
+        }
+        Err(_) => {
+            // errors writing to stderr are ignored, as the default hook does
+            let _ = writeln!(io::stderr(), "{}", message);
+        }
+    }
+}
+
+/// Returns the message of a panic payload, as the default hook prints it
+fn payload_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&'static str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "Box<dyn Any>"
+    }
+}
@@ -0,0 +1,143 @@
+use core::any::type_name;
+use core::fmt;
+use core::str::FromStr;
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+use super::Secret;
+
+/// Error returned when parsing a secret fails
+///
+/// Contrary to the errors of many `FromStr` implementations, a `ParseError` never contains the
+/// input that failed to parse, only the name of the type it was supposed to be parsed into. It is
+/// returned by the `FromStr` implementation of `Secret<T>` in place of `T::Err`.
+///
+/// Errors returned by `reveal_parse` additionally carry the message of the underlying error, with
+/// any occurrence of the input replaced by `...`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    target: &'static str,
+    #[cfg(feature = "alloc")]
+    reason: Option<String>,
+}
+
+impl ParseError {
+    #[inline]
+    fn new<U>() -> ParseError {
+        ParseError {
+            target: type_name::<U>(),
+            #[cfg(feature = "alloc")]
+            reason: None,
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn with_reason<U, E: fmt::Display>(input: &str, err: E) -> ParseError {
+        let mut reason = err.to_string();
+        if !input.is_empty() {
+            reason = reason.replace(input, "...");
+        }
+
+        ParseError {
+            target: type_name::<U>(),
+            reason: Some(reason),
+        }
+    }
+
+    /// Returns the name of the type the secret was supposed to be parsed into
+    #[inline]
+    pub fn target(&self) -> &'static str {
+        self.target
+    }
+
+    /// Returns the scrubbed message of the underlying error, if available
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "a confidential value could not be parsed as `{}`",
+            self.target
+        )?;
+
+        #[cfg(feature = "alloc")]
+        {
+            if let Some(ref reason) = self.reason {
+                write!(f, ": {}", reason)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+impl<T: FromStr> FromStr for Secret<T> {
+    type Err = ParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        T::from_str(s)
+            .map(Secret::wrap)
+            .map_err(|_| ParseError::new::<T>())
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn parse_scrubbed<U, P>(s: &str) -> Result<Secret<U, P>, ParseError>
+where
+    U: FromStr,
+    U::Err: fmt::Display,
+{
+    U::from_str(s)
+        .map(Secret::wrap)
+        .map_err(|err| ParseError::with_reason::<U, _>(s, err))
+}
+
+#[cfg(feature = "alloc")]
+impl<P> Secret<String, P> {
+    /// Parses the held value into a new secret
+    ///
+    /// The input is **revealed** to `U::from_str` only. On failure, the returned error contains the
+    /// message of the underlying error with the input scrubbed from it.
+    #[inline]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `reveal_with` and a `RevealToken`")
+    )]
+    pub fn reveal_parse<U>(&self) -> Result<Secret<U, P>, ParseError>
+    where
+        U: FromStr,
+        U::Err: fmt::Display,
+    {
+        parse_scrubbed(&self.1)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> Secret<&str, P> {
+    /// Parses the held value into a new secret
+    ///
+    /// See `Secret<String>::reveal_parse`.
+    #[inline]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `reveal_with` and a `RevealToken`")
+    )]
+    pub fn reveal_parse<U>(&self) -> Result<Secret<U, P>, ParseError>
+    where
+        U: FromStr,
+        U::Err: fmt::Display,
+    {
+        parse_scrubbed(self.1)
+    }
+}
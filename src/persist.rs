@@ -0,0 +1,112 @@
+//! Writing secrets to files
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::string::String;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::vec::Vec;
+
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+
+use super::source::file_error;
+use super::Secret;
+
+/// Counter for unique temporary file names within the process
+static TEMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns a temporary path next to `path`, so that renaming it to `path` is atomic
+fn temp_path(path: &Path) -> PathBuf {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    path.with_file_name(format!(
+        ".{}.{}.{}.tmp",
+        name,
+        process::id(),
+        TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ))
+}
+
+/// Creates a file that only the current user can read and write
+fn create_private(path: &Path) -> io::Result<File> {
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+
+    options.open(path)
+}
+
+/// Atomically replaces the file at `path` with `data`
+///
+/// Errors contain the path, but never any part of `data`.
+fn write_file_secure(path: &Path, data: &[u8]) -> io::Result<()> {
+    // a file created elsewhere would inherit the permissions of its directory
+    if cfg!(not(unix)) {
+        return Err(file_error(
+            io::ErrorKind::Unsupported,
+            path,
+            format!(
+                "could not write secret file `{}`: restricting access is only supported on Unix",
+                path.display()
+            ),
+        ));
+    }
+
+    let temp = temp_path(path);
+    let result = create_private(&temp).and_then(|mut file| {
+        file.write_all(data)?;
+        file.sync_all()?;
+        drop(file);
+
+        fs::rename(&temp, path)
+    });
+
+    if let Err(err) = result {
+        let _ = fs::remove_file(&temp);
+        return Err(file_error(
+            err.kind(),
+            path,
+            format!("could not write secret file `{}`: {}", path.display(), err),
+        ));
+    }
+
+    // make the rename itself durable; failing to do so does not undo the write
+    #[cfg(unix)]
+    {
+        if let Some(dir) = path.parent().and_then(|dir| File::open(dir).ok()) {
+            let _ = dir.sync_all();
+        }
+    }
+
+    Ok(())
+}
+
+impl<P> Secret<String, P> {
+    /// Writes the held value to a file that only the current user can access
+    ///
+    /// The value is written to a temporary file in the same directory, which is synced and then
+    /// renamed to `path`, so `path` either keeps its old contents or contains the complete secret.
+    /// The file is created with mode `0o600`. Access cannot be restricted on other platforms than
+    /// Unix yet, including Windows, where this fails with `io::ErrorKind::Unsupported` instead of
+    /// writing a file that inherits the permissions of its directory.
+    #[inline]
+    pub fn write_to_file_secure<F: AsRef<Path>>(&self, path: F) -> io::Result<()> {
+        write_file_secure(path.as_ref(), self.1.as_bytes())
+    }
+}
+
+impl<P> Secret<Vec<u8>, P> {
+    /// Writes the held bytes to a file that only the current user can access
+    ///
+    /// See `Secret::<String>::write_to_file_secure`.
+    #[inline]
+    pub fn write_to_file_secure<F: AsRef<Path>>(&self, path: F) -> io::Result<()> {
+        write_file_secure(path.as_ref(), &self.1)
+    }
+}
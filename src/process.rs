@@ -0,0 +1,58 @@
+//! Passing secrets to child processes
+
+use std::ffi::OsStr;
+use std::process::Command;
+use std::string::String;
+
+use super::{audit, Secret};
+
+/// Extension trait for passing secrets to a `std::process::Command`
+///
+/// The secret is revealed inside these methods only, so spawning a process does not need a
+/// `reveal` at every call site. Note that the `Debug` output of `Command` includes its environment
+/// and arguments, so a command holding a secret should not be logged.
+pub trait SecretCommandExt {
+    /// Sets the environment variable `key` to a secret string for the child process
+    fn secret_env<K: AsRef<OsStr>>(&mut self, key: K, value: &Secret<String>) -> &mut Command;
+
+    /// Sets the environment variable `key` to a secret value for the child process
+    fn secret_env_os<K: AsRef<OsStr>, V: AsRef<OsStr> + ?Sized>(
+        &mut self,
+        key: K,
+        value: &Secret<V>,
+    ) -> &mut Command;
+
+    /// Passes a secret as a command line argument
+    ///
+    /// **Warning:** on most systems, the arguments of every process can be read by all users of
+    /// the system, e.g. through `/proc/<pid>/cmdline` or `ps`. Prefer `secret_env` or passing the
+    /// secret through a file or the child's stdin whenever the child program supports it.
+    fn secret_arg<V: AsRef<OsStr> + ?Sized>(&mut self, value: &Secret<V>) -> &mut Command;
+}
+
+impl SecretCommandExt for Command {
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn secret_env<K: AsRef<OsStr>>(&mut self, key: K, value: &Secret<String>) -> &mut Command {
+        audit::revealed::<String>();
+        self.env(key, &value.1)
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn secret_env_os<K: AsRef<OsStr>, V: AsRef<OsStr> + ?Sized>(
+        &mut self,
+        key: K,
+        value: &Secret<V>,
+    ) -> &mut Command {
+        audit::revealed::<V>();
+        self.env(key, value.1.as_ref())
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn secret_arg<V: AsRef<OsStr> + ?Sized>(&mut self, value: &Secret<V>) -> &mut Command {
+        audit::revealed::<V>();
+        self.arg(value.1.as_ref())
+    }
+}
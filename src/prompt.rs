@@ -0,0 +1,46 @@
+use std::io::{self, IsTerminal, Write};
+use std::string::String;
+
+use super::Secret;
+
+/// Asks for a secret on the terminal, without echoing the input
+///
+/// `msg` is printed as the prompt. If standard input is not a terminal, e.g. because the input is
+/// piped into the program, a single line is read from it instead. Either way, the trailing newline
+/// is removed and the input is wrapped in a `Secret` right away.
+pub fn prompt(msg: &str) -> io::Result<Secret<String>> {
+    let stdin = io::stdin();
+    if stdin.is_terminal() {
+        rpassword::prompt_password(msg).map(Secret::wrap)
+    } else {
+        let mut stderr = io::stderr();
+        stderr.write_all(msg.as_bytes())?;
+        stderr.flush()?;
+        Secret::read_line_from(stdin.lock())
+    }
+}
+
+/// Asks for a secret twice, e.g. when setting a new password
+///
+/// Fails with `io::ErrorKind::InvalidInput` if the two inputs differ. The comparison is done in
+/// constant time and the inputs are not part of the error.
+pub fn prompt_confirm(msg: &str, confirm_msg: &str) -> io::Result<Secret<String>> {
+    let first = prompt(msg)?;
+    let second = prompt(confirm_msg)?;
+    confirm(first, &second)
+}
+
+/// Returns `first` if it matches `second`
+pub(crate) fn confirm(
+    first: Secret<String>,
+    second: &Secret<String>,
+) -> io::Result<Secret<String>> {
+    if first.verify(&second.1) {
+        Ok(first)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "the entered secrets do not match",
+        ))
+    }
+}
@@ -0,0 +1,241 @@
+//! A common interface for the places secrets are loaded from
+//!
+//! Applications often read some secrets from the environment, some from files and some from a
+//! remote secret manager. `SecretProvider` abstracts over those, and `ChainProvider` combines
+//! several providers, e.g. to let environment variables override files during development:
+//!
+//! ```rust
+//! use sec::provider::{ChainProvider, EnvProvider, FileProvider, SecretProvider};
+//!
+//! let provider = ChainProvider::new()
+//!     .with(EnvProvider)
+//!     .with(FileProvider::new("/run/secrets"));
+//!
+//! let api_key = provider.get("SEC_DOC_MISSING_API_KEY")?;
+//! assert!(api_key.is_none());
+//! # Ok::<(), sec::provider::ProviderError>(())
+//! ```
+
+use std::boxed::Box;
+use std::error::Error;
+use std::fmt;
+#[cfg(feature = "async")]
+use std::future::Future;
+use std::io;
+use std::path::{Path, PathBuf};
+#[cfg(feature = "async")]
+use std::pin::Pin;
+use std::string::String;
+use std::vec::Vec;
+
+use super::source::is_file_name;
+use super::{EnvError, Secret};
+
+/// Error returned by a `SecretProvider`
+///
+/// Describes the provider and the key that was looked up, never any part of a value.
+/// Implementations of `SecretProvider` must ensure the same for the underlying cause.
+#[derive(Debug)]
+pub struct ProviderError {
+    provider: String,
+    key: String,
+    cause: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ProviderError {
+    /// Creates an error of `provider` while looking up `key`
+    ///
+    /// `provider` describes where the secret was looked up, e.g. `"environment"`.
+    pub fn new<P: Into<String>, K: Into<String>>(provider: P, key: K) -> ProviderError {
+        ProviderError {
+            provider: provider.into(),
+            key: key.into(),
+            cause: None,
+        }
+    }
+
+    /// Sets the underlying cause, returned by `Error::source`
+    pub fn with_cause<E: Into<Box<dyn Error + Send + Sync>>>(mut self, cause: E) -> ProviderError {
+        self.cause = Some(cause.into());
+        self
+    }
+
+    /// Returns the description of the provider
+    #[inline]
+    pub fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    /// Returns the key that was looked up
+    #[inline]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl fmt::Display for ProviderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "could not get secret `{}` from {}",
+            self.key, self.provider
+        )?;
+        match &self.cause {
+            Some(cause) => write!(f, ": {}", cause),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Error for ProviderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.cause {
+            Some(cause) => Some(&**cause),
+            None => None,
+        }
+    }
+}
+
+/// A source of secrets, looked up by key
+pub trait SecretProvider {
+    /// Looks up the secret `key`, returning `None` if this provider does not have it
+    fn get(&self, key: &str) -> Result<Option<Secret<String>>, ProviderError>;
+}
+
+/// Future returned by `AsyncSecretProvider::get_async`
+#[cfg(feature = "async")]
+pub type ProviderFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Option<Secret<String>>, ProviderError>> + Send + 'a>>;
+
+/// An asynchronous source of secrets, e.g. a remote secret manager
+///
+/// Implementations must not block the executor. Of the providers of this crate, only
+/// `EnvProvider` implements it, as looking up a variable does not block. Providers reading files,
+/// such as `FileProvider` or `ChainProvider`, have to be called through the `spawn_blocking` of the
+/// executor instead, e.g. `tokio::task::spawn_blocking`.
+#[cfg(feature = "async")]
+pub trait AsyncSecretProvider {
+    /// Looks up the secret `key`, returning `None` if this provider does not have it
+    fn get_async<'a>(&'a self, key: &'a str) -> ProviderFuture<'a>;
+}
+
+/// Provides secrets from environment variables, named by the key
+///
+/// A single trailing newline is removed, see `Secret::from_env`. Variables that are not valid
+/// unicode are an error.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EnvProvider;
+
+impl SecretProvider for EnvProvider {
+    fn get(&self, key: &str) -> Result<Option<Secret<String>>, ProviderError> {
+        match Secret::from_env(key) {
+            Ok(secret) => Ok(Some(secret)),
+            Err(EnvError::NotPresent { .. }) => Ok(None),
+            Err(err) => Err(ProviderError::new("environment", key).with_cause(err)),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncSecretProvider for EnvProvider {
+    fn get_async<'a>(&'a self, key: &'a str) -> ProviderFuture<'a> {
+        Box::pin(async move { self.get(key) })
+    }
+}
+
+/// Provides secrets from the files in a directory, named by the key
+///
+/// A single trailing newline is removed, see `Secret::from_file`. Keys that are not plain file
+/// names, such as `../key`, are an error.
+#[derive(Clone, Debug)]
+pub struct FileProvider {
+    base_dir: PathBuf,
+}
+
+impl FileProvider {
+    /// Creates a provider reading files in `base_dir`
+    #[inline]
+    pub fn new<D: Into<PathBuf>>(base_dir: D) -> FileProvider {
+        FileProvider {
+            base_dir: base_dir.into(),
+        }
+    }
+
+    /// Returns the directory secrets are read from
+    #[inline]
+    pub fn base_dir(&self) -> &Path {
+        &self.base_dir
+    }
+
+    fn error(&self, key: &str) -> ProviderError {
+        ProviderError::new(format!("files in `{}`", self.base_dir.display()), key)
+    }
+}
+
+impl SecretProvider for FileProvider {
+    fn get(&self, key: &str) -> Result<Option<Secret<String>>, ProviderError> {
+        if !is_file_name(key) {
+            return Err(self.error(key).with_cause("not a plain file name"));
+        }
+
+        match Secret::from_file(self.base_dir.join(key)) {
+            Ok(secret) => Ok(Some(secret)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(self.error(key).with_cause(err)),
+        }
+    }
+}
+
+/// Tries several providers in order, returning the first secret found
+///
+/// An error of any provider is returned right away, without trying the remaining ones, so that a
+/// broken provider does not silently fall back to a secret meant to be overridden.
+#[derive(Default)]
+pub struct ChainProvider(Vec<Box<dyn SecretProvider + Send + Sync>>);
+
+impl ChainProvider {
+    /// Creates a chain without any providers
+    #[inline]
+    pub fn new() -> ChainProvider {
+        ChainProvider(Vec::new())
+    }
+
+    /// Appends a provider, to be tried after all others
+    #[inline]
+    pub fn with<T: SecretProvider + Send + Sync + 'static>(mut self, provider: T) -> ChainProvider {
+        self.push(provider);
+        self
+    }
+
+    /// Appends a provider, to be tried after all others
+    #[inline]
+    pub fn push<T: SecretProvider + Send + Sync + 'static>(&mut self, provider: T) {
+        self.0.push(Box::new(provider));
+    }
+}
+
+impl From<Vec<Box<dyn SecretProvider + Send + Sync>>> for ChainProvider {
+    #[inline]
+    fn from(providers: Vec<Box<dyn SecretProvider + Send + Sync>>) -> ChainProvider {
+        ChainProvider(providers)
+    }
+}
+
+impl SecretProvider for ChainProvider {
+    fn get(&self, key: &str) -> Result<Option<Secret<String>>, ProviderError> {
+        for provider in &self.0 {
+            if let Some(secret) = provider.get(key)? {
+                return Ok(Some(secret));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl fmt::Debug for ChainProvider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ChainProvider")
+            .field("providers", &self.0.len())
+            .finish()
+    }
+}
@@ -0,0 +1,85 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec};
+
+use super::Secret;
+
+#[cfg(feature = "alloc")]
+pub(crate) const ALPHANUMERIC: &[u8; 62] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+/// Fills `buf` with random bytes from the operating system
+///
+/// Panics if no randomness is available, as there is no sensible way to continue.
+#[inline]
+fn fill(buf: &mut [u8]) {
+    getrandom::getrandom(buf).expect("failed to obtain random data from the operating system")
+}
+
+impl<const N: usize> Secret<[u8; N]> {
+    /// Creates a new secret filled with random bytes
+    ///
+    /// The bytes are obtained from the operating system through `getrandom` and written directly
+    /// into the secret.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operating system fails to provide random data.
+    #[inline]
+    pub fn random() -> Secret<[u8; N]> {
+        let mut secret = Secret::wrap([0; N]);
+        fill(&mut secret.1);
+        secret
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Secret<vec::Vec<u8>> {
+    /// Creates a new secret of `len` random bytes
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operating system fails to provide random data.
+    #[inline]
+    pub fn random_bytes(len: usize) -> Secret<vec::Vec<u8>> {
+        let mut secret = Secret::wrap(vec![0; len]);
+        fill(&mut secret.1);
+        secret
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Secret<String> {
+    /// Creates a new secret string of `len` random alphanumeric characters (`[A-Za-z0-9]`)
+    ///
+    /// Every character carries `log2(62) ≈ 5.95` bits of entropy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operating system fails to provide random data.
+    pub fn random_alphanumeric(len: usize) -> Secret<String> {
+        let mut secret = Secret::wrap(String::with_capacity(len));
+        push_alphanumeric(&mut secret.1, len);
+        secret
+    }
+}
+
+/// Appends `len` random alphanumeric characters to `s`
+///
+/// # Panics
+///
+/// Panics if the operating system fails to provide random data.
+#[cfg(feature = "alloc")]
+pub(crate) fn push_alphanumeric(s: &mut String, len: usize) {
+    let mut buf = [0u8; 64];
+    let mut remaining = len;
+
+    while remaining > 0 {
+        fill(&mut buf);
+
+        // rejection sampling avoids a modulo bias, 248 is the largest multiple of 62 below 256
+        for &b in buf.iter().filter(|&&b| b < 248).take(remaining) {
+            s.push(ALPHANUMERIC[(b % 62) as usize] as char);
+            remaining -= 1;
+        }
+    }
+}
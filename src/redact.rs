@@ -0,0 +1,130 @@
+use core::any::type_name;
+use core::fmt;
+use std::boxed::Box;
+use std::error::Error;
+
+use super::audit;
+
+/// Wraps an error that may contain a confidential value
+///
+/// Many errors embed the input that caused them, e.g. a deserialization error quoting the invalid
+/// token. `SecretError` hides the wrapped error: `Display` and `Debug` only show its type, and
+/// `source` yields an equally redacted copy of the underlying chain. The original error is only
+/// available through `reveal_source`.
+pub struct SecretError<E> {
+    err: E,
+    chain: Option<Box<RedactedSource>>,
+}
+
+impl<E: Error> SecretError<E> {
+    /// Wraps an error
+    #[inline]
+    pub fn new(err: E) -> SecretError<E> {
+        let chain = RedactedSource::chain(err.source());
+        SecretError { err, chain }
+    }
+}
+
+impl<E> SecretError<E> {
+    /// **Reveals** the wrapped error
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "reveal only in approved modules, see `RevealToken`")
+    )]
+    pub fn reveal_source(&self) -> &E {
+        audit::revealed::<E>();
+        &self.err
+    }
+}
+
+impl<E: Error> From<E> for SecretError<E> {
+    #[inline]
+    fn from(err: E) -> SecretError<E> {
+        SecretError::new(err)
+    }
+}
+
+impl<E> fmt::Display for SecretError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "error while processing a confidential value ({})",
+            type_name::<E>()
+        )
+    }
+}
+
+impl<E> fmt::Debug for SecretError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SecretError<{}>(...)", type_name::<E>())
+    }
+}
+
+impl<E: Error> Error for SecretError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.chain
+            .as_deref()
+            .map(|err| err as &(dyn Error + 'static))
+    }
+}
+
+/// Redacted stand-in for an error further down the chain of a `SecretError`
+///
+/// Only the length of the chain is kept; the errors themselves are not.
+pub(crate) struct RedactedSource(Option<Box<RedactedSource>>);
+
+impl RedactedSource {
+    /// Creates a redacted copy of `err` and its chain of sources
+    #[cfg(feature = "anyhow")]
+    pub(crate) fn new(err: &(dyn Error + 'static)) -> RedactedSource {
+        RedactedSource(RedactedSource::chain(err.source()))
+    }
+
+    fn chain(source: Option<&(dyn Error + 'static)>) -> Option<Box<RedactedSource>> {
+        source.map(|err| Box::new(RedactedSource(RedactedSource::chain(err.source()))))
+    }
+}
+
+impl fmt::Display for RedactedSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("error while processing a confidential value")
+    }
+}
+
+impl fmt::Debug for RedactedSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SecretError(...)")
+    }
+}
+
+impl Error for RedactedSource {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.as_deref().map(|err| err as &(dyn Error + 'static))
+    }
+}
+
+/// Alias of `SecretError`, for use as a `#[source]` field in error enums
+///
+/// ```ignore
+/// #[derive(Debug, thiserror::Error)]
+/// enum ClientError {
+///     #[error("could not connect to the database")]
+///     Connect(#[from] Redacted<postgres::Error>),
+/// }
+/// ```
+pub type Redacted<E> = SecretError<E>;
+
+/// Extension trait for wrapping the error of a `Result` in a `SecretError`
+pub trait RedactErr<T, E> {
+    /// Wraps the error, if any, in a `SecretError`
+    fn redact_err(self) -> Result<T, SecretError<E>>;
+}
+
+impl<T, E: Error> RedactErr<T, E> for Result<T, E> {
+    #[inline]
+    fn redact_err(self) -> Result<T, SecretError<E>> {
+        self.map_err(SecretError::new)
+    }
+}
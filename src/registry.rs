@@ -0,0 +1,177 @@
+//! Process-wide registry of secrets to be scrubbed from output
+//!
+//! Some output is produced outside of the control of `Secret`, e.g. panic messages of errors that
+//! embed a token, or log lines written by other libraries. Secrets registered here are removed
+//! from such output by `scrub` and `scrub_bytes`, and by the facilities of this crate that build
+//! on them, such as `install_scrubbing_panic_hook`.
+//!
+//! ```rust
+//! use sec::{registry, Secret};
+//!
+//! let token = Secret::new("tok-abcdef".to_owned());
+//! registry::register(&token)?;
+//!
+//! let mut line = "request failed: invalid token tok-abcdef".to_owned();
+//! registry::scrub(&mut line);
+//! assert_eq!(line, "request failed: invalid token [REDACTED]");
+//!
+//! registry::unregister(&token);
+//! # Ok::<(), sec::registry::RegistryFull>(())
+//! ```
+//!
+//! All occurrences of all registered secrets are found in a single pass over the input. The
+//! automaton doing so is rebuilt on the first scrub after the set of secrets changed, so
+//! registering is cheap, but should not happen once per scrub.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::mem;
+use std::string::String;
+use std::sync::{OnceLock, PoisonError, RwLock, TryLockError};
+use std::vec::Vec;
+
+use super::matcher::Matcher;
+use super::scrub::Patterns;
+use super::Secret;
+
+/// Maximum number of secrets in the registry, unless changed through `set_max_entries`
+pub const DEFAULT_MAX_ENTRIES: usize = 1024;
+
+struct Registry {
+    patterns: Patterns,
+    max_entries: usize,
+    // built from `patterns` on first use, reset whenever they change
+    matcher: OnceLock<Matcher>,
+}
+
+impl Registry {
+    #[inline]
+    fn matcher(&self) -> &Matcher {
+        self.matcher
+            .get_or_init(|| Matcher::new(self.patterns.iter()))
+    }
+}
+
+static REGISTRY: RwLock<Registry> = RwLock::new(Registry {
+    patterns: Patterns::new(),
+    max_entries: DEFAULT_MAX_ENTRIES,
+    matcher: OnceLock::new(),
+});
+
+/// Error returned when registering a secret while the registry is full
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RegistryFull {
+    max_entries: usize,
+}
+
+impl RegistryFull {
+    /// Returns the maximum number of secrets in the registry at the time of the error
+    #[inline]
+    pub fn max_entries(&self) -> usize {
+        self.max_entries
+    }
+}
+
+impl fmt::Display for RegistryFull {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "the secret registry is full ({} entries)",
+            self.max_entries
+        )
+    }
+}
+
+impl std::error::Error for RegistryFull {}
+
+/// Registers a secret, without revealing it
+///
+/// The registry keeps a copy of the secret's bytes until it is unregistered; with the `zeroize`
+/// feature, the copy is wiped then. Registering the same value twice has no effect; empty values
+/// are ignored.
+pub fn register<T: AsRef<[u8]> + ?Sized, P>(secret: &Secret<T, P>) -> Result<(), RegistryFull> {
+    let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+    let pattern = secret.1.as_ref();
+
+    if pattern.is_empty() || registry.patterns.contains(pattern) {
+        return Ok(());
+    }
+    if registry.patterns.len() >= registry.max_entries {
+        return Err(RegistryFull {
+            max_entries: registry.max_entries,
+        });
+    }
+
+    registry.patterns.add(pattern);
+    registry.matcher = OnceLock::new();
+    Ok(())
+}
+
+/// Removes a secret from the registry, returning whether it was registered
+pub fn unregister<T: AsRef<[u8]> + ?Sized, P>(secret: &Secret<T, P>) -> bool {
+    let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+
+    let removed = registry.patterns.remove(secret.1.as_ref());
+    if removed {
+        registry.matcher = OnceLock::new();
+    }
+    removed
+}
+
+/// Sets the maximum number of secrets in the registry
+///
+/// Secrets already registered are kept, even if there are more than `max_entries`.
+pub fn set_max_entries(max_entries: usize) {
+    REGISTRY
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .max_entries = max_entries;
+}
+
+/// Replaces all occurrences of registered secrets in `s` with `[REDACTED]`
+///
+/// Overlapping occurrences of different secrets are replaced as a whole. Returns whether anything
+/// was replaced. If a secret is not valid UTF-8 and was found cutting through a character, the
+/// remains of the character are replaced with `U+FFFD`.
+pub fn scrub(s: &mut String) -> bool {
+    let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+    scrub_string(registry.matcher(), s)
+}
+
+/// Replaces all occurrences of registered secrets in `buf` with `[REDACTED]`
+///
+/// Returns whether anything was replaced; see `scrub`.
+pub fn scrub_bytes(buf: &mut Vec<u8>) -> bool {
+    let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+    registry.matcher().scrub_bytes(buf)
+}
+
+/// Returns `s` with all registered secrets replaced by `[REDACTED]`, without blocking
+///
+/// Returns `None` if the registry is currently locked for writing, e.g. when called from a panic
+/// hook while the panicking thread is registering a secret.
+pub(crate) fn try_scrub_str(s: &str) -> Option<Cow<'_, str>> {
+    let registry = match REGISTRY.try_read() {
+        Ok(registry) => registry,
+        Err(TryLockError::Poisoned(err)) => err.into_inner(),
+        Err(TryLockError::WouldBlock) => return None,
+    };
+
+    let mut scrubbed = String::from(s);
+    Some(if scrub_string(registry.matcher(), &mut scrubbed) {
+        Cow::Owned(scrubbed)
+    } else {
+        Cow::Borrowed(s)
+    })
+}
+
+fn scrub_string(matcher: &Matcher, s: &mut String) -> bool {
+    let mut buf = mem::take(s).into_bytes();
+    let scrubbed = matcher.scrub_bytes(&mut buf);
+
+    *s = match String::from_utf8(buf) {
+        Ok(s) => s,
+        Err(err) => String::from_utf8_lossy(err.as_bytes()).into_owned(),
+    };
+    scrubbed
+}
@@ -0,0 +1,102 @@
+use core::fmt;
+use core::mem;
+
+#[cfg(feature = "subtle")]
+use subtle::ConstantTimeEq;
+
+use super::{audit, Secret};
+
+/// A secret during rotation, holding the current and optionally the previous value
+///
+/// While credentials such as signing keys are rotated, values created with the previous key
+/// should still be accepted for a grace period. `verify_any` checks a candidate against both.
+pub struct RotatingSecret<T> {
+    current: Secret<T>,
+    previous: Option<Secret<T>>,
+}
+
+impl<T> RotatingSecret<T> {
+    /// Creates a new rotating secret without a previous value
+    #[inline]
+    pub fn new(current: T) -> RotatingSecret<T> {
+        RotatingSecret {
+            current: Secret::wrap(current),
+            previous: None,
+        }
+    }
+
+    /// Makes `new` the current value, keeping the current value as the previous one
+    ///
+    /// The old previous value, if any, is dropped.
+    #[inline]
+    pub fn rotate(&mut self, new: T) {
+        let current = mem::replace(&mut self.current, Secret::wrap(new));
+        self.previous = Some(current);
+    }
+
+    /// Drops the previous value, e.g. once the grace period is over
+    #[inline]
+    pub fn clear_previous(&mut self) {
+        self.previous = None;
+    }
+
+    /// Returns the current value
+    #[inline]
+    pub fn current(&self) -> &Secret<T> {
+        &self.current
+    }
+
+    /// Returns the previous value, if any
+    #[inline]
+    pub fn previous(&self) -> Option<&Secret<T>> {
+        self.previous.as_ref()
+    }
+
+    /// **Reveals** the current value
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `current` and `reveal_with`")
+    )]
+    pub fn reveal_current(&self) -> &T {
+        audit::revealed::<T>();
+        &self.current.1
+    }
+}
+
+impl<T: AsRef<[u8]>> RotatingSecret<T> {
+    /// Checks whether `candidate` matches the current or the previous value, in constant time
+    ///
+    /// Both values are always compared, so the timing does not tell which one matched. With the
+    /// `subtle` feature, the comparison uses `subtle::ConstantTimeEq`. As with `verify`, the
+    /// lengths of the values are not considered confidential.
+    pub fn verify_any(&self, candidate: &[u8]) -> bool {
+        #[cfg(feature = "subtle")]
+        {
+            let matches_previous = match self.previous {
+                Some(ref previous) => previous.1.as_ref().ct_eq(candidate),
+                None => 0.into(),
+            };
+            bool::from(self.current.1.as_ref().ct_eq(candidate) | matches_previous)
+        }
+
+        #[cfg(not(feature = "subtle"))]
+        {
+            let matches_current = super::ct::eq_bytes(self.current.1.as_ref(), candidate);
+            let matches_previous = match self.previous {
+                Some(ref previous) => super::ct::eq_bytes(previous.1.as_ref(), candidate),
+                None => false,
+            };
+            matches_current | matches_previous
+        }
+    }
+}
+
+impl<T> fmt::Debug for RotatingSecret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RotatingSecret")
+            .field("has_previous", &self.previous.is_some())
+            .finish()
+    }
+}
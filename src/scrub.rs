@@ -0,0 +1,302 @@
+//! Removing secrets from output streams
+
+use std::fmt;
+use std::io::{self, Write};
+use std::vec::Vec;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use super::Secret;
+
+/// Replacement written in place of a secret
+pub(crate) const REDACTED: &[u8] = b"[REDACTED]";
+
+/// Set of byte patterns to be replaced with `[REDACTED]`
+#[derive(Default)]
+pub(crate) struct Patterns(Vec<Vec<u8>>);
+
+impl Patterns {
+    /// Creates an empty set of patterns
+    #[inline]
+    pub(crate) const fn new() -> Patterns {
+        Patterns(Vec::new())
+    }
+
+    /// Adds a pattern; empty patterns are ignored
+    pub(crate) fn add(&mut self, pattern: &[u8]) {
+        if !pattern.is_empty() && !self.contains(pattern) {
+            self.0.push(pattern.to_vec());
+        }
+    }
+
+    /// Removes a pattern, returning whether it was present
+    pub(crate) fn remove(&mut self, pattern: &[u8]) -> bool {
+        match self.0.iter().position(|p| p == pattern) {
+            Some(index) => {
+                #[cfg(feature = "zeroize")]
+                self.0[index].zeroize();
+                self.0.swap_remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns whether `pattern` is in the set
+    #[inline]
+    pub(crate) fn contains(&self, pattern: &[u8]) -> bool {
+        self.0.iter().any(|p| p == pattern)
+    }
+
+    /// Returns the number of patterns
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns an iterator over the patterns
+    #[inline]
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &[u8]> {
+        self.0.iter().map(|p| p.as_slice())
+    }
+
+    /// Returns whether `rest` could still turn into a match once more data arrives
+    #[inline]
+    fn is_partial(&self, rest: &[u8]) -> bool {
+        self.0
+            .iter()
+            .any(|p| p.len() > rest.len() && p.starts_with(rest))
+    }
+
+    /// Returns the length of the longest pattern `rest` starts with, if any
+    #[inline]
+    fn longest_match(&self, rest: &[u8]) -> Option<usize> {
+        self.0
+            .iter()
+            .filter(|p| rest.starts_with(p))
+            .map(|p| p.len())
+            .max()
+    }
+
+    /// Copies `input` to `out`, replacing all occurrences of a pattern
+    ///
+    /// Overlapping occurrences, even of different patterns, are replaced as a single range, so
+    /// that no part of either is left over, as with `registry::scrub`. Unless `at_end` is set, a
+    /// trailing part of `input` that could still turn into a match, or extend one, once more data
+    /// arrives is held back. Returns the number of bytes of `input` that were consumed.
+    pub(crate) fn scrub(&self, input: &[u8], out: &mut Vec<u8>, at_end: bool) -> usize {
+        let mut pos = 0;
+
+        'outer: while pos < input.len() {
+            let rest = &input[pos..];
+
+            if !at_end && self.is_partial(rest) {
+                break;
+            }
+
+            match self.longest_match(rest) {
+                Some(len) => {
+                    // extend the range by any occurrence starting inside of it
+                    let mut end = pos + len;
+                    let mut start = pos + 1;
+                    while start < end {
+                        let rest = &input[start..];
+                        if !at_end && self.is_partial(rest) {
+                            break 'outer;
+                        }
+                        if let Some(len) = self.longest_match(rest) {
+                            end = end.max(start + len);
+                        }
+                        start += 1;
+                    }
+
+                    out.extend_from_slice(REDACTED);
+                    pos = end;
+                }
+                None => {
+                    out.push(rest[0]);
+                    pos += 1;
+                }
+            }
+        }
+
+        pos
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Patterns {
+    fn drop(&mut self) {
+        for pattern in &mut self.0 {
+            pattern.zeroize();
+        }
+    }
+}
+
+/// Wraps a writer, replacing registered secrets in everything written to it with `[REDACTED]`
+///
+/// This is a last line of defense for output that is formatted by code outside of our control,
+/// e.g. a library logging the connection string it was handed:
+///
+/// ```rust
+/// use sec::{ScrubWriter, Secret};
+/// use std::io::Write;
+///
+/// let password = Secret::new("hunter2".to_owned());
+///
+/// let mut out = ScrubWriter::new(Vec::new());
+/// out.add_secret(&password);
+/// write!(out, "connecting to postgres://alice:{}@db", password.reveal())?;
+///
+/// assert_eq!(out.finish()?, b"connecting to postgres://alice:[REDACTED]@db");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// Secrets that straddle the boundary between two calls to `write` are found as well: a trailing
+/// part of the written data that could be the start of a secret is held back until enough data
+/// has arrived to tell. `flush` does not write such a partial match either; it is only written by
+/// `finish`, or when the `ScrubWriter` is dropped.
+///
+/// `write` always accepts the whole buffer. Scrubbed data the inner writer did not accept is kept
+/// and written first on the next call; an error doing so is returned before any new data is
+/// accepted, so nothing is written twice. Errors on drop are ignored, as with `BufWriter`.
+pub struct ScrubWriter<W: Write> {
+    inner: Option<W>,
+    patterns: Patterns,
+    // written data that has not been scrubbed yet, as it may be the start of a secret
+    pending: Vec<u8>,
+    // scrubbed data not yet accepted by `inner`
+    out: Vec<u8>,
+}
+
+impl<W: Write> ScrubWriter<W> {
+    /// Wraps `inner`, without any secrets registered yet
+    #[inline]
+    pub fn new(inner: W) -> ScrubWriter<W> {
+        ScrubWriter {
+            inner: Some(inner),
+            patterns: Patterns::default(),
+            pending: Vec::new(),
+            out: Vec::new(),
+        }
+    }
+
+    /// Registers a secret to be scrubbed, without revealing it
+    ///
+    /// The writer keeps a copy of the secret's bytes, which is wiped on drop with the `zeroize`
+    /// feature. Only data written after registering is guaranteed to be scrubbed of the secret.
+    pub fn add_secret<T: AsRef<[u8]> + ?Sized, P>(&mut self, secret: &Secret<T, P>) {
+        self.patterns.add(secret.1.as_ref());
+    }
+
+    /// Registers a secret to be scrubbed, see `add_secret`
+    #[inline]
+    pub fn with_secret<T: AsRef<[u8]> + ?Sized, P>(mut self, secret: &Secret<T, P>) -> Self {
+        self.add_secret(secret);
+        self
+    }
+
+    /// Returns a reference to the inner writer
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        self.inner
+            .as_ref()
+            .expect("inner writer is only taken on finish")
+    }
+
+    /// Returns a mutable reference to the inner writer
+    ///
+    /// Writing to it directly bypasses the scrubbing.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        self.inner
+            .as_mut()
+            .expect("inner writer is only taken on finish")
+    }
+
+    /// Writes all held back data and returns the inner writer
+    pub fn finish(mut self) -> io::Result<W> {
+        self.scrub(true);
+        self.write_out()?;
+        self.get_mut().flush()?;
+        Ok(self
+            .inner
+            .take()
+            .expect("inner writer is only taken on finish"))
+    }
+
+    /// Moves everything in `pending` that can no longer become part of a secret to `out`
+    fn scrub(&mut self, at_end: bool) {
+        let consumed = self.patterns.scrub(&self.pending, &mut self.out, at_end);
+        self.pending.drain(..consumed);
+    }
+
+    /// Writes `out` to the inner writer
+    fn write_out(&mut self) -> io::Result<()> {
+        let inner = self
+            .inner
+            .as_mut()
+            .expect("inner writer is only taken on finish");
+        let mut written = 0;
+
+        let res = loop {
+            if written == self.out.len() {
+                break Ok(());
+            }
+
+            match inner.write(&self.out[written..]) {
+                Ok(0) => {
+                    break Err(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "failed to write scrubbed data",
+                    ))
+                }
+                Ok(n) => written += n,
+                Err(ref err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => break Err(err),
+            }
+        };
+
+        self.out.drain(..written);
+        res
+    }
+}
+
+impl<W: Write> Write for ScrubWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_out()?;
+
+        self.pending.extend_from_slice(buf);
+        self.scrub(false);
+
+        // `buf` has been accepted, an error writing it out is returned by the next call
+        let _ = self.write_out();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_out()?;
+        self.get_mut().flush()
+    }
+}
+
+impl<W: Write> Drop for ScrubWriter<W> {
+    fn drop(&mut self) {
+        if self.inner.is_some() {
+            self.scrub(true);
+            let _ = self.write_out();
+        }
+
+        #[cfg(feature = "zeroize")]
+        self.pending.zeroize();
+    }
+}
+
+impl<W: Write> fmt::Debug for ScrubWriter<W> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ScrubWriter")
+            .field("secrets", &self.patterns.0.len())
+            .finish()
+    }
+}
@@ -0,0 +1,127 @@
+use std::fmt;
+use std::iter::FromIterator;
+use std::vec::Vec;
+
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use super::Secret;
+
+/// A set of secrets with constant-time membership checks
+///
+/// Checking a presented API key against a `HashSet` of valid keys, or any early-exit search,
+/// leaks through its timing which entry was matched, and how much of it. A `SecretSet` compares
+/// the candidate against every entry with `subtle::ConstantTimeEq` instead, and accumulates the
+/// result without branching on any match:
+///
+/// ```rust
+/// use sec::{Secret, SecretSet};
+///
+/// let mut keys = SecretSet::new();
+/// keys.insert(Secret::new(String::from("key-of-alice")));
+/// keys.insert(Secret::new(String::from("key-of-bob")));
+///
+/// assert!(keys.contains(b"key-of-bob"));
+/// assert_eq!(keys.position(b"key-of-bob"), Some(1));
+/// assert!(!keys.contains(b"key-of-eve"));
+/// ```
+///
+/// Every check costs `O(n·len)`, for `n` entries of length `len`, regardless of where or whether
+/// the candidate is found. As with `Secret::verify`, the lengths of the entries are not considered
+/// confidential: entries with a different length than the candidate are skipped immediately.
+#[derive(Default)]
+pub struct SecretSet {
+    entries: Vec<Secret<Vec<u8>>>,
+}
+
+impl SecretSet {
+    /// Creates an empty set
+    #[inline]
+    pub fn new() -> SecretSet {
+        SecretSet::default()
+    }
+
+    /// Returns the number of entries
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the set has no entries
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Adds a secret, returning whether it was not present yet
+    ///
+    /// Entries are added at the end, so the positions of existing entries do not change.
+    pub fn insert<T: Into<Vec<u8>>>(&mut self, secret: Secret<T>) -> bool {
+        let secret = secret.map_revealed(Into::into);
+        if self.contains(&secret.1) {
+            return false;
+        }
+        self.entries.push(secret);
+        true
+    }
+
+    /// Removes the entry matching `candidate`, returning whether there was one
+    ///
+    /// The positions of the following entries decrease by one.
+    pub fn remove(&mut self, candidate: &[u8]) -> bool {
+        match self.position(candidate) {
+            Some(index) => {
+                self.entries.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Checks whether `candidate` matches any entry, in constant time
+    #[inline]
+    pub fn contains(&self, candidate: &[u8]) -> bool {
+        self.position(candidate).is_some()
+    }
+
+    /// Returns the position of the entry matching `candidate`, in constant time
+    pub fn position(&self, candidate: &[u8]) -> Option<usize> {
+        let mut found = Choice::from(0);
+        let mut position = 0u64;
+
+        for (index, entry) in self.entries.iter().enumerate() {
+            let matches = entry.1.as_slice().ct_eq(candidate);
+            position = u64::conditional_select(&position, &(index as u64), matches);
+            found |= matches;
+        }
+
+        if bool::from(found) {
+            Some(position as usize)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Into<Vec<u8>>> FromIterator<Secret<T>> for SecretSet {
+    fn from_iter<I: IntoIterator<Item = Secret<T>>>(iter: I) -> SecretSet {
+        let mut set = SecretSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Into<Vec<u8>>> Extend<Secret<T>> for SecretSet {
+    fn extend<I: IntoIterator<Item = Secret<T>>>(&mut self, iter: I) {
+        for secret in iter {
+            self.insert(secret);
+        }
+    }
+}
+
+impl fmt::Debug for SecretSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SecretSet")
+            .field("len", &self.entries.len())
+            .finish()
+    }
+}
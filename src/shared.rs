@@ -0,0 +1,78 @@
+use std::fmt;
+use std::mem;
+use std::sync::{Arc, PoisonError, RwLock};
+
+use super::Secret;
+
+/// A shared handle to a secret that can be replaced, e.g. when it is rotated
+///
+/// Clones of a `Secret` handed to many tasks cannot be updated. Clones of a `SharedSecret` all
+/// refer to the same value, so a `swap` is seen by every holder:
+///
+/// ```rust
+/// use sec::{Secret, SharedSecret};
+///
+/// let token = SharedSecret::new("hunter2".to_owned());
+/// let handle = token.clone();
+///
+/// let current = handle.get();
+/// token.swap(Secret::new("hunter3".to_owned()));
+///
+/// assert_eq!(handle.get().reveal(), "hunter3");
+/// assert_eq!(current.reveal(), "hunter2");
+/// ```
+///
+/// `get` returns the value as an `Arc`, which keeps it alive and unchanged while it is in use, so
+/// a revealed reference can never observe a swap.
+pub struct SharedSecret<T> {
+    current: Arc<RwLock<Arc<Secret<T>>>>,
+}
+
+impl<T> SharedSecret<T> {
+    /// Creates a new shared secret
+    #[inline]
+    pub fn new(value: T) -> SharedSecret<T> {
+        SharedSecret::from(Secret::wrap(value))
+    }
+
+    /// Returns the current value
+    #[inline]
+    pub fn get(&self) -> Arc<Secret<T>> {
+        self.current
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Replaces the value for all handles, returning the previous one
+    #[inline]
+    pub fn swap(&self, new: Secret<T>) -> Arc<Secret<T>> {
+        let mut current = self.current.write().unwrap_or_else(PoisonError::into_inner);
+        mem::replace(&mut *current, Arc::new(new))
+    }
+}
+
+impl<T> From<Secret<T>> for SharedSecret<T> {
+    #[inline]
+    fn from(secret: Secret<T>) -> SharedSecret<T> {
+        SharedSecret {
+            current: Arc::new(RwLock::new(Arc::new(secret))),
+        }
+    }
+}
+
+impl<T> Clone for SharedSecret<T> {
+    /// Returns another handle to the same value
+    #[inline]
+    fn clone(&self) -> SharedSecret<T> {
+        SharedSecret {
+            current: Arc::clone(&self.current),
+        }
+    }
+}
+
+impl<T> fmt::Debug for SharedSecret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SharedSecret(...)")
+    }
+}
@@ -0,0 +1,258 @@
+//! Loading secrets from the environment and from files
+
+use std::env::{self, VarError};
+use std::error::Error;
+use std::ffi::OsString;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, Read};
+use std::path::{Path, PathBuf};
+use std::string::String;
+use std::vec::Vec;
+
+use super::Secret;
+
+/// Error returned when reading a secret from an environment variable fails
+///
+/// Only contains the name of the variable, never any part of its value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EnvError {
+    /// The variable is not set
+    NotPresent {
+        /// Name of the variable
+        name: String,
+    },
+    /// The value of the variable is not valid unicode
+    NotUnicode {
+        /// Name of the variable
+        name: String,
+    },
+}
+
+impl fmt::Display for EnvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EnvError::NotPresent { name } => {
+                write!(f, "environment variable `{}` is not set", name)
+            }
+            EnvError::NotUnicode { name } => write!(
+                f,
+                "environment variable `{}` does not contain valid unicode",
+                name
+            ),
+        }
+    }
+}
+
+impl Error for EnvError {}
+
+/// Payload of the `io::Error`s of the file APIs, which keeps the path for `Error::Io`
+#[derive(Debug)]
+pub(crate) struct FileError {
+    pub(crate) path: PathBuf,
+    message: String,
+}
+
+impl fmt::Display for FileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for FileError {}
+
+/// Creates an error of `kind` about the file at `path`
+pub(crate) fn file_error(kind: io::ErrorKind, path: &Path, message: String) -> io::Error {
+    io::Error::new(
+        kind,
+        FileError {
+            path: path.into(),
+            message,
+        },
+    )
+}
+
+/// Removes a single trailing `\n` or `\r\n`
+pub(crate) fn trim_newline(s: &mut String) {
+    if s.ends_with('\n') {
+        s.pop();
+        if s.ends_with('\r') {
+            s.pop();
+        }
+    }
+}
+
+impl Secret<String> {
+    /// Reads a secret from the environment variable `name`
+    ///
+    /// A single trailing newline is removed, since variables are often set from the contents of a
+    /// file.
+    pub fn from_env(name: &str) -> Result<Secret<String>, EnvError> {
+        match env::var(name) {
+            Ok(mut value) => {
+                trim_newline(&mut value);
+                Ok(Secret::wrap(value))
+            }
+            Err(VarError::NotPresent) => Err(EnvError::NotPresent { name: name.into() }),
+            Err(VarError::NotUnicode(_)) => Err(EnvError::NotUnicode { name: name.into() }),
+        }
+    }
+
+    /// Reads a secret from the environment variable `name`, if it is set to a valid unicode value
+    ///
+    /// See `from_env`.
+    #[inline]
+    pub fn from_env_opt(name: &str) -> Option<Secret<String>> {
+        Secret::from_env(name).ok()
+    }
+}
+
+impl Secret<OsString> {
+    /// Reads a secret from the environment variable `name`, without requiring valid unicode
+    ///
+    /// Unlike `Secret::<String>::from_env`, the value is returned as it is, including any trailing
+    /// newline.
+    pub fn from_env_os(name: &str) -> Result<Secret<OsString>, EnvError> {
+        env::var_os(name)
+            .map(Secret::wrap)
+            .ok_or_else(|| EnvError::NotPresent { name: name.into() })
+    }
+}
+
+/// Returns whether `name` is a plain file name, which cannot refer to a file in another directory
+pub(crate) fn is_file_name(name: &str) -> bool {
+    !name.is_empty() && name != "." && name != ".." && !name.contains(&['/', '\\', '\0'][..])
+}
+
+/// Default size limit of `from_file` and `from_file_bytes`, in bytes
+pub const DEFAULT_FILE_LIMIT: u64 = 1024 * 1024;
+
+/// Reads at most `limit` bytes from the file at `path`
+///
+/// Errors contain the path, but never any part of the contents.
+pub(crate) fn read_file(path: &Path, limit: u64) -> io::Result<Vec<u8>> {
+    File::open(path)
+        .and_then(|file| Secret::read_from_limited(file, limit))
+        .map(|secret| secret.1)
+        .map_err(|err| {
+            file_error(
+                err.kind(),
+                path,
+                format!("could not read secret file `{}`: {}", path.display(), err),
+            )
+        })
+}
+
+impl Secret<String> {
+    /// Reads a secret from a file, e.g. one mounted by Docker or Kubernetes
+    ///
+    /// Exactly one trailing newline is removed. Files larger than `DEFAULT_FILE_LIMIT` are
+    /// rejected, which usually means the path points to the wrong file.
+    #[inline]
+    pub fn from_file<F: AsRef<Path>>(path: F) -> io::Result<Secret<String>> {
+        Secret::from_file_limited(path, DEFAULT_FILE_LIMIT)
+    }
+
+    /// Reads a secret from a file that is at most `limit` bytes large
+    ///
+    /// See `from_file`.
+    pub fn from_file_limited<F: AsRef<Path>>(path: F, limit: u64) -> io::Result<Secret<String>> {
+        let path = path.as_ref();
+        let mut value = String::from_utf8(read_file(path, limit)?).map_err(|_| {
+            file_error(
+                io::ErrorKind::InvalidData,
+                path,
+                format!("secret file `{}` is not valid UTF-8", path.display()),
+            )
+        })?;
+
+        trim_newline(&mut value);
+        Ok(Secret::wrap(value))
+    }
+}
+
+impl Secret<Vec<u8>> {
+    /// Reads a binary secret from a file
+    ///
+    /// Unlike `Secret::<String>::from_file`, the contents are returned unmodified. Files larger
+    /// than `DEFAULT_FILE_LIMIT` are rejected.
+    #[inline]
+    pub fn from_file_bytes<F: AsRef<Path>>(path: F) -> io::Result<Secret<Vec<u8>>> {
+        Secret::from_file_bytes_limited(path, DEFAULT_FILE_LIMIT)
+    }
+
+    /// Reads a binary secret from a file that is at most `limit` bytes large
+    #[inline]
+    pub fn from_file_bytes_limited<F: AsRef<Path>>(
+        path: F,
+        limit: u64,
+    ) -> io::Result<Secret<Vec<u8>>> {
+        read_file(path.as_ref(), limit).map(Secret::wrap)
+    }
+}
+
+impl Secret<Vec<u8>> {
+    /// Reads a secret from `reader` until the end of the input
+    ///
+    /// The data is read directly into the secret. Errors never contain any of the bytes read so
+    /// far.
+    pub fn read_from<R: Read>(mut reader: R) -> io::Result<Secret<Vec<u8>>> {
+        let mut buf = Secret::wrap(Vec::new());
+        reader.read_to_end(&mut buf.1)?;
+        Ok(buf)
+    }
+
+    /// Reads a secret of at most `limit` bytes from `reader` until the end of the input
+    ///
+    /// Fails with `io::ErrorKind::InvalidData` if the input is longer. See `read_from`.
+    pub fn read_from_limited<R: Read>(reader: R, limit: u64) -> io::Result<Secret<Vec<u8>>> {
+        let buf = Secret::read_from(reader.take(limit.saturating_add(1)))?;
+        if buf.1.len() as u64 > limit {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("secret is larger than {} bytes", limit),
+            ));
+        }
+
+        Ok(buf)
+    }
+}
+
+impl Secret<String> {
+    /// Reads a single line from `reader` into a secret, removing the trailing newline
+    ///
+    /// Fails with `io::ErrorKind::UnexpectedEof` if the input is empty. Errors never contain any of
+    /// the bytes read so far.
+    pub fn read_line_from<R: BufRead>(mut reader: R) -> io::Result<Secret<String>> {
+        let mut line = Secret::wrap(String::new());
+        if reader.read_line(&mut line.1)? == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "reached the end of the input before reading a secret",
+            ));
+        }
+
+        trim_newline(&mut line.1);
+        Ok(line)
+    }
+}
+
+/// Reads a secret from an environment variable at runtime
+///
+/// Shorthand for `Secret::<String>::from_env(name)`. Unlike `env!`, the variable is read when the
+/// macro is executed, not when it is compiled.
+///
+/// ```rust
+/// use sec::secret_env;
+///
+/// std::env::set_var("SEC_DOC_API_KEY", "THIS-SHOULD-BE-SECRET\n");
+///
+/// let api_key = secret_env!("SEC_DOC_API_KEY").unwrap();
+/// assert_eq!(api_key.reveal(), "THIS-SHOULD-BE-SECRET");
+/// ```
+#[macro_export]
+macro_rules! secret_env {
+    ($name:expr) => {
+        $crate::Secret::<::std::string::String>::from_env($name)
+    };
+}
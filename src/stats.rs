@@ -0,0 +1,173 @@
+//! Runtime statistics of secrets
+//!
+//! With the `stats` feature, creations and reveals of secrets are counted, both in total and per
+//! type of the held value:
+//!
+//! ```rust
+//! use sec::{stats, Secret};
+//!
+//! let token: Secret<String> = String::from("THIS-SHOULD-BE-SECRET").into();
+//! assert_eq!(token.reveal_str().len(), 21);
+//!
+//! let snapshot = stats::snapshot();
+//! assert!(snapshot.of::<String>().created >= 1);
+//! assert!(snapshot.of::<String>().revealed >= 1);
+//! ```
+//!
+//! A creation is counted for every secret built by a non-`const` constructor or conversion of
+//! this crate, e.g. `From<T>`, `Clone`, `Default`, `from_env`, `decode_base64` or
+//! `map_revealed`. `Secret::new` and `Secret::with_placeholder` stay `const fn`s and are not
+//! counted; use `From` for secrets that should be. Copies of `Copy` secrets and references
+//! such as those returned by `as_ref` are not counted either. Reveals are counted for all
+//! functions reported to the `audit` hook, i.e. all but `reveal_const`.
+//!
+//! Drops are not counted, so the number of secrets currently alive is not available. Counting them
+//! would require `Secret` to implement `Drop`, which it cannot: a type implementing `Drop` can
+//! neither be `Copy`, as `Secret<T>` is for `Copy` values, nor give up its value by moving it out,
+//! as `reveal_into` and `map_revealed` do.
+//!
+//! Counters are process-global. Per-type counters are looked up by the name of the type, which
+//! takes a read lock on a global map; they are only meant for debugging and auditing, not for
+//! production hot paths.
+
+use core::any::type_name;
+use std::boxed::Box;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{PoisonError, RwLock};
+
+/// Counters of a single type, or of all types
+struct Counters {
+    created: AtomicU64,
+    revealed: AtomicU64,
+}
+
+impl Counters {
+    const fn new() -> Counters {
+        Counters {
+            created: AtomicU64::new(0),
+            revealed: AtomicU64::new(0),
+        }
+    }
+
+    fn load(&self) -> TypeStats {
+        TypeStats {
+            created: self.created.load(Ordering::Relaxed),
+            revealed: self.revealed.load(Ordering::Relaxed),
+        }
+    }
+
+    fn reset(&self) {
+        self.created.store(0, Ordering::Relaxed);
+        self.revealed.store(0, Ordering::Relaxed);
+    }
+}
+
+static TOTAL: Counters = Counters::new();
+
+/// Counters per type name, populated on first use; entries are leaked, as there is one per type
+static TYPES: RwLock<BTreeMap<&'static str, &'static Counters>> = RwLock::new(BTreeMap::new());
+
+/// Returns the counters for values of type `T`
+fn counters<T: ?Sized>() -> &'static Counters {
+    let name = type_name::<T>();
+    if let Some(counters) = TYPES
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(name)
+    {
+        return counters;
+    }
+
+    TYPES
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(name)
+        .or_insert_with(|| Box::leak(Box::new(Counters::new())))
+}
+
+/// Counts the creation of a secret holding a `T`
+#[inline]
+pub(crate) fn created<T: ?Sized>() {
+    TOTAL.created.fetch_add(1, Ordering::Relaxed);
+    counters::<T>().created.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Counts a reveal of a secret holding a `T`
+#[inline]
+pub(crate) fn revealed<T: ?Sized>() {
+    TOTAL.revealed.fetch_add(1, Ordering::Relaxed);
+    counters::<T>().revealed.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Counters of secrets holding a single type
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TypeStats {
+    /// Number of secrets created, not including those created by `Secret::new`
+    pub created: u64,
+    /// Number of reveals
+    pub revealed: u64,
+}
+
+/// Counters of all secrets, as returned by `snapshot`
+///
+/// Secrets created by the `const fn`s `Secret::new` and `Secret::with_placeholder` are missing
+/// from `created`, as are reveals through `Secret::reveal_const` from `revealed`. There is no count
+/// of the secrets currently alive.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StatsSnapshot {
+    /// Number of secrets created, not including those created by `Secret::new`
+    pub created: u64,
+    /// Number of reveals
+    pub revealed: u64,
+    /// Counters per name of the held type, as returned by `core::any::type_name`
+    ///
+    /// Types without any creations or reveals since the last `reset` are omitted.
+    pub types: BTreeMap<&'static str, TypeStats>,
+}
+
+impl StatsSnapshot {
+    /// Returns the counters of secrets holding a `T`
+    #[inline]
+    pub fn of<T: ?Sized>(&self) -> TypeStats {
+        self.types
+            .get(type_name::<T>())
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// Returns the current values of all counters
+///
+/// `Secret::new` and the other `const` constructors are not counted, see `StatsSnapshot`.
+///
+/// The counters are read one by one while other threads may update them, so the totals are not
+/// guaranteed to match the sum of the per-type counters.
+pub fn snapshot() -> StatsSnapshot {
+    let total = TOTAL.load();
+    let types = TYPES
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .map(|(&name, counters)| (name, counters.load()))
+        .filter(|(_, stats)| *stats != TypeStats::default())
+        .collect();
+
+    StatsSnapshot {
+        created: total.created,
+        revealed: total.revealed,
+        types,
+    }
+}
+
+/// Resets all counters to zero
+pub fn reset() {
+    TOTAL.reset();
+    for counters in TYPES
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .values()
+    {
+        counters.reset();
+    }
+}
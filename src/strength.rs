@@ -0,0 +1,138 @@
+use std::string::{String, ToString};
+use std::vec::Vec;
+
+use zxcvbn::time_estimates::CrackTimeSeconds;
+
+use super::Secret;
+
+/// Estimated time to crack a password in different attack scenarios, in seconds
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CrackTimes {
+    /// Online attack against a service limiting the rate to 100 guesses per hour
+    pub online_throttling_100_per_hour: f64,
+    /// Online attack against a service without rate limiting, at 10 guesses per second
+    pub online_no_throttling_10_per_second: f64,
+    /// Offline attack against a slow hash such as bcrypt, at 10⁴ guesses per second
+    pub offline_slow_hashing_1e4_per_second: f64,
+    /// Offline attack against a fast hash such as SHA-256, at 10¹⁰ guesses per second
+    pub offline_fast_hashing_1e10_per_second: f64,
+}
+
+/// Strength of a password, estimated by zxcvbn
+///
+/// Unlike the result of zxcvbn itself, the report contains nothing derived from the password:
+/// the matched parts of the password are dropped, and the feedback consists of zxcvbn's fixed
+/// warnings and suggestions only.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StrengthReport {
+    score: u8,
+    guesses_log10: f64,
+    crack_times: CrackTimes,
+    warning: Option<String>,
+    suggestions: Vec<String>,
+}
+
+impl StrengthReport {
+    /// Returns the score from 0 (too guessable) to 4 (very unguessable)
+    #[inline]
+    pub fn score(&self) -> u8 {
+        self.score
+    }
+
+    /// Returns the base 10 logarithm of the estimated number of guesses needed
+    #[inline]
+    pub fn guesses_log10(&self) -> f64 {
+        self.guesses_log10
+    }
+
+    /// Returns the estimated time to crack the password
+    #[inline]
+    pub fn crack_times(&self) -> &CrackTimes {
+        &self.crack_times
+    }
+
+    /// Returns a warning explaining what makes the password weak, if any
+    #[inline]
+    pub fn warning(&self) -> Option<&str> {
+        self.warning.as_deref()
+    }
+
+    /// Returns suggestions for a stronger password
+    #[inline]
+    pub fn suggestions(&self) -> &[String] {
+        &self.suggestions
+    }
+}
+
+fn seconds(time: CrackTimeSeconds) -> f64 {
+    match time {
+        CrackTimeSeconds::Integer(secs) => secs as f64,
+        CrackTimeSeconds::Float(secs) => secs,
+    }
+}
+
+impl<P> Secret<String, P> {
+    /// Estimates the strength of the password, without revealing it
+    #[inline]
+    pub fn strength(&self) -> StrengthReport {
+        self.strength_with_inputs(&[])
+    }
+
+    /// Estimates the strength of the password, treating `user_inputs` as easily guessable
+    ///
+    /// `user_inputs` are words an attacker may know, such as the user name or email address.
+    pub fn strength_with_inputs(&self, user_inputs: &[&str]) -> StrengthReport {
+        let entropy = match zxcvbn::zxcvbn(&self.1, user_inputs) {
+            Ok(entropy) => entropy,
+            // an empty password is guessed right away
+            Err(_) => {
+                return StrengthReport {
+                    score: 0,
+                    guesses_log10: 0.0,
+                    crack_times: CrackTimes {
+                        online_throttling_100_per_hour: 0.0,
+                        online_no_throttling_10_per_second: 0.0,
+                        offline_slow_hashing_1e4_per_second: 0.0,
+                        offline_fast_hashing_1e10_per_second: 0.0,
+                    },
+                    warning: None,
+                    suggestions: Vec::new(),
+                }
+            }
+        };
+
+        let crack_times = entropy.crack_times();
+        let (warning, suggestions) = match entropy.feedback() {
+            Some(feedback) => (
+                feedback.warning().map(|warning| warning.to_string()),
+                feedback
+                    .suggestions()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+            ),
+            None => (None, Vec::new()),
+        };
+
+        StrengthReport {
+            score: entropy.score(),
+            guesses_log10: entropy.guesses_log10(),
+            crack_times: CrackTimes {
+                online_throttling_100_per_hour: seconds(
+                    crack_times.online_throttling_100_per_hour(),
+                ),
+                online_no_throttling_10_per_second: seconds(
+                    crack_times.online_no_throttling_10_per_second(),
+                ),
+                offline_slow_hashing_1e4_per_second: seconds(
+                    crack_times.offline_slow_hashing_1e4_per_second(),
+                ),
+                offline_fast_hashing_1e10_per_second: seconds(
+                    crack_times.offline_fast_hashing_1e10_per_second(),
+                ),
+            },
+            warning,
+            suggestions,
+        }
+    }
+}
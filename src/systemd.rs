@@ -0,0 +1,123 @@
+//! Loading credentials passed by systemd
+//!
+//! Services started with `LoadCredential=`, `SetCredential=` or `ImportCredential=` receive their
+//! secrets as files in the directory named by `$CREDENTIALS_DIRECTORY`, which, unlike environment
+//! variables, are neither inherited by child processes nor visible in `/proc`:
+//!
+//! ```ignore
+//! let db_password = match sec::systemd::credential("db_password") {
+//!     Err(CredentialError::NoCredentialsDirectory) => secret_env!("DB_PASSWORD")?,
+//!     result => result?,
+//! };
+//! ```
+
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::string::String;
+use std::vec::Vec;
+
+use super::source::{is_file_name, read_file, trim_newline};
+use super::{Secret, DEFAULT_FILE_LIMIT};
+
+/// Error returned when loading a credential fails
+///
+/// Only contains the name of the credential, never any part of its contents.
+#[derive(Debug)]
+pub enum CredentialError {
+    /// `CREDENTIALS_DIRECTORY` is not set, i.e. the process was not started by systemd with any
+    /// credentials
+    NoCredentialsDirectory,
+    /// The name is empty or not a plain file name, e.g. `../foo`
+    InvalidName {
+        /// Name of the credential
+        name: String,
+    },
+    /// There is no credential with this name
+    NotFound {
+        /// Name of the credential
+        name: String,
+    },
+    /// The credential is not valid UTF-8
+    NotUnicode {
+        /// Name of the credential
+        name: String,
+    },
+    /// Reading the credential failed
+    Io {
+        /// Name of the credential
+        name: String,
+        /// The underlying error
+        err: io::Error,
+    },
+}
+
+impl fmt::Display for CredentialError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CredentialError::NoCredentialsDirectory => {
+                f.write_str("`CREDENTIALS_DIRECTORY` is not set")
+            }
+            CredentialError::InvalidName { name } => {
+                write!(f, "`{}` is not a valid credential name", name)
+            }
+            CredentialError::NotFound { name } => write!(f, "credential `{}` not found", name),
+            CredentialError::NotUnicode { name } => {
+                write!(f, "credential `{}` is not valid UTF-8", name)
+            }
+            CredentialError::Io { name, err } => {
+                write!(f, "could not read credential `{}`: {}", name, err)
+            }
+        }
+    }
+}
+
+impl Error for CredentialError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CredentialError::Io { err, .. } => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the path of the credential `name`
+fn path(name: &str) -> Result<PathBuf, CredentialError> {
+    let dir = env::var_os("CREDENTIALS_DIRECTORY")
+        .filter(|dir| !dir.is_empty())
+        .ok_or(CredentialError::NoCredentialsDirectory)?;
+
+    if !is_file_name(name) {
+        return Err(CredentialError::InvalidName { name: name.into() });
+    }
+    Ok(PathBuf::from(dir).join(name))
+}
+
+/// Loads the binary credential `name`, unmodified
+///
+/// Credentials larger than `DEFAULT_FILE_LIMIT` are rejected.
+pub fn credential_bytes(name: &str) -> Result<Secret<Vec<u8>>, CredentialError> {
+    read_file(&path(name)?, DEFAULT_FILE_LIMIT)
+        .map(Secret::wrap)
+        .map_err(|err| match err.kind() {
+            io::ErrorKind::NotFound => CredentialError::NotFound { name: name.into() },
+            _ => CredentialError::Io {
+                name: name.into(),
+                err,
+            },
+        })
+}
+
+/// Loads the credential `name`, removing a single trailing newline
+///
+/// See `credential_bytes`.
+pub fn credential(name: &str) -> Result<Secret<String>, CredentialError> {
+    let bytes = credential_bytes(name)?;
+    let mut value = String::from_utf8(bytes.1)
+        .map_err(|_| CredentialError::NotUnicode { name: name.into() })?;
+
+    trim_newline(&mut value);
+    Ok(Secret::wrap(value))
+}
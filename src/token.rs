@@ -0,0 +1,107 @@
+//! API tokens with a public prefix and a checksum
+//!
+//! Tokens minted here look like `acme_4Jq0sVx7...Zp2kQ1`: a prefix identifying the issuer, an
+//! underscore, a random alphanumeric body and six characters of checksum. Secret scanners can
+//! recognize leaked tokens by their prefix, and rule out false positives by the checksum, without
+//! access to any stored secret:
+//!
+//! ```rust
+//! use sec::token;
+//!
+//! let api_key = token::mint("acme", 30);
+//! assert!(token::validate_format(api_key.reveal()));
+//! assert!(!token::validate_format("acme_not0a0real0token"));
+//! ```
+//!
+//! The checksum is a CRC32 of the prefix, underscore and body, encoded in base62. It only detects
+//! typos and random strings; anyone can compute it, so it does not authenticate a token.
+
+use alloc::string::String;
+
+use super::random::{push_alphanumeric, ALPHANUMERIC};
+use super::Secret;
+
+/// Number of characters of the checksum
+const CHECKSUM_LEN: usize = 6;
+
+/// Computes the CRC32 (IEEE) of `data`
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &b in data {
+        crc ^= u32::from(b);
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+/// Encodes the checksum of `payload` as six base62 characters
+fn checksum(payload: &str) -> [u8; CHECKSUM_LEN] {
+    let mut crc = crc32(payload.as_bytes());
+    let mut encoded = [0u8; CHECKSUM_LEN];
+
+    // 62⁶ exceeds 2³², so six characters hold every checksum
+    for c in encoded.iter_mut().rev() {
+        *c = ALPHANUMERIC[(crc % 62) as usize];
+        crc /= 62;
+    }
+    encoded
+}
+
+/// Returns whether `prefix` is a valid token prefix, e.g. `acme` or `acme_live`
+fn is_valid_prefix(prefix: &str) -> bool {
+    !prefix.is_empty()
+        && !prefix.starts_with('_')
+        && !prefix.ends_with('_')
+        && prefix
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'_')
+}
+
+/// Mints a new token with `prefix` and `random_len` random characters
+///
+/// Every random character carries `log2(62) ≈ 5.95` bits of entropy, so a `random_len` of 30 or
+/// more is recommended. The token is built inside the returned secret.
+///
+/// # Panics
+///
+/// Panics if `prefix` is empty or contains characters other than ASCII letters, digits and inner
+/// underscores, if `random_len` is zero, or if the operating system fails to provide random data.
+pub fn mint(prefix: &str, random_len: usize) -> Secret<String> {
+    assert!(is_valid_prefix(prefix), "invalid token prefix `{}`", prefix);
+    assert!(random_len > 0, "tokens need a random part");
+
+    let mut token = Secret::wrap(String::with_capacity(
+        prefix.len() + 1 + random_len + CHECKSUM_LEN,
+    ));
+    token.1.push_str(prefix);
+    token.1.push('_');
+    push_alphanumeric(&mut token.1, random_len);
+
+    let checksum = checksum(&token.1);
+    token.1.extend(checksum.iter().map(|&c| char::from(c)));
+    token
+}
+
+/// Checks whether `candidate` has the format of a token minted by `mint`
+///
+/// The prefix, the characters of the body and the checksum are checked. No secret is needed,
+/// so this is suitable for scanning logs or repositories for leaked tokens.
+pub fn validate_format(candidate: &str) -> bool {
+    let underscore = match candidate.rfind('_') {
+        Some(pos) => pos,
+        None => return false,
+    };
+    let rest = &candidate[underscore + 1..];
+
+    if !is_valid_prefix(&candidate[..underscore])
+        || rest.len() <= CHECKSUM_LEN
+        || !rest.bytes().all(|b| b.is_ascii_alphanumeric())
+    {
+        return false;
+    }
+
+    let (payload, sum) = candidate.split_at(candidate.len() - CHECKSUM_LEN);
+    checksum(payload) == sum.as_bytes()
+}
@@ -0,0 +1,118 @@
+//! Revealing several secrets at once
+
+use super::{audit, Secret};
+
+/// A secret or a reference to a secret, as an element of a tuple passed to `RevealTuple`
+pub trait RevealPart {
+    /// Type of the held value
+    type Target: ?Sized;
+
+    /// Type of the value returned by `reveal_part`
+    type Output;
+
+    /// **Reveals** the held value, by value for `Secret<T>` and by reference for `&Secret<T>`
+    fn reveal_part(self) -> Self::Output;
+
+    /// **Reveals** the held value by returning a reference
+    fn reveal_part_ref(&self) -> &Self::Target;
+}
+
+impl<T, P> RevealPart for Secret<T, P> {
+    type Target = T;
+    type Output = T;
+
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn reveal_part(self) -> T {
+        audit::revealed::<T>();
+        self.1
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn reveal_part_ref(&self) -> &T {
+        audit::revealed::<T>();
+        &self.1
+    }
+}
+
+impl<'a, T: ?Sized, P> RevealPart for &'a Secret<T, P> {
+    type Target = T;
+    type Output = &'a T;
+
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn reveal_part(self) -> &'a T {
+        audit::revealed::<T>();
+        &self.1
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    fn reveal_part_ref(&self) -> &T {
+        audit::revealed::<T>();
+        &self.1
+    }
+}
+
+/// Extension trait for revealing all secrets of a tuple at once
+///
+/// Implemented for tuples of up to six secrets or references to secrets:
+///
+/// ```rust
+/// use sec::{RevealTuple, Secret};
+///
+/// let client_id = Secret::new("CLIENT-ID");
+/// let client_secret = Secret::new("THIS-SHOULD-BE-SECRET".to_owned());
+///
+/// let (id, secret) = (client_id, &client_secret).reveal_all();
+/// assert_eq!(id, "CLIENT-ID");
+/// assert_eq!(secret, "THIS-SHOULD-BE-SECRET");
+/// ```
+pub trait RevealTuple {
+    /// Tuple of the revealed values
+    type Output;
+
+    /// Tuple of references to the revealed values
+    type Refs<'a>
+    where
+        Self: 'a;
+
+    /// **Reveals** all held values, owned secrets by value and borrowed ones by reference
+    fn reveal_all(self) -> Self::Output;
+
+    /// **Reveals** references to all held values
+    fn reveal_all_ref(&self) -> Self::Refs<'_>;
+}
+
+macro_rules! impl_reveal_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: RevealPart),+> RevealTuple for ($($name,)+) {
+            type Output = ($($name::Output,)+);
+            type Refs<'a> = ($(&'a $name::Target,)+) where Self: 'a;
+
+            #[inline]
+            #[cfg_attr(feature = "audit", track_caller)]
+            #[allow(non_snake_case)]
+            fn reveal_all(self) -> Self::Output {
+                let ($($name,)+) = self;
+                ($($name.reveal_part(),)+)
+            }
+
+            #[inline]
+            #[cfg_attr(feature = "audit", track_caller)]
+            #[allow(non_snake_case)]
+            fn reveal_all_ref(&self) -> Self::Refs<'_> {
+                let ($($name,)+) = self;
+                ($($name.reveal_part_ref(),)+)
+            }
+        }
+    };
+}
+
+impl_reveal_tuple!(A);
+impl_reveal_tuple!(A, B);
+impl_reveal_tuple!(A, B, C);
+impl_reveal_tuple!(A, B, C, D);
+impl_reveal_tuple!(A, B, C, D, E);
+impl_reveal_tuple!(A, B, C, D, E, F);
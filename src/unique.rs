@@ -0,0 +1,86 @@
+use alloc::sync::Arc;
+use core::fmt;
+
+use super::{audit, Secret};
+
+/// A secret that cannot be cloned
+///
+/// A `Secret<T>` is `Clone` if `T` is, so copies of a credential easily end up all over a
+/// program. A `UniqueSecret` implements neither `Clone` nor `Copy`; the only way to use it in
+/// more than one place is `share`, which moves it into an `Arc`:
+///
+/// ```rust
+/// use sec::UniqueSecret;
+///
+/// let key = UniqueSecret::new(String::from("hunter2"));
+/// let shared = key.share();
+/// let handle = shared.clone();
+///
+/// assert_eq!(handle.reveal(), "hunter2");
+/// assert_eq!(std::sync::Arc::strong_count(&shared), 2);
+/// ```
+///
+/// Cloning does not compile:
+///
+/// ```compile_fail
+/// let key = sec::UniqueSecret::new(String::from("hunter2"));
+/// let copy: sec::UniqueSecret<String> = key.clone();
+/// ```
+///
+/// Conversions from and to `Secret<T>` are available through `From`, but have to be explicit.
+#[repr(transparent)]
+pub struct UniqueSecret<T>(Secret<T>);
+
+impl<T> UniqueSecret<T> {
+    /// Creates a new unique secret
+    #[inline]
+    pub fn new(val: T) -> UniqueSecret<T> {
+        UniqueSecret(Secret::wrap(val))
+    }
+
+    /// Creates a secret immutable reference
+    #[inline]
+    pub fn as_ref(&self) -> Secret<&T> {
+        self.0.as_ref()
+    }
+
+    /// **Reveals** the held value by unwrapping
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `Secret::from` and `reveal_into_with`")
+    )]
+    pub fn reveal_into(self) -> T {
+        audit::revealed::<T>();
+        self.0 .1
+    }
+
+    /// Moves the secret into an `Arc`, to share it explicitly
+    ///
+    /// The number of holders is the `Arc::strong_count` of the result.
+    #[inline]
+    pub fn share(self) -> Arc<Secret<T>> {
+        Arc::new(self.0)
+    }
+}
+
+impl<T> From<Secret<T>> for UniqueSecret<T> {
+    #[inline]
+    fn from(secret: Secret<T>) -> UniqueSecret<T> {
+        UniqueSecret(secret)
+    }
+}
+
+impl<T> From<UniqueSecret<T>> for Secret<T> {
+    #[inline]
+    fn from(secret: UniqueSecret<T>) -> Secret<T> {
+        secret.0
+    }
+}
+
+impl<T> fmt::Debug for UniqueSecret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("UniqueSecret(...)")
+    }
+}
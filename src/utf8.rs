@@ -0,0 +1,82 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::{self, Utf8Error};
+
+use super::{Ellipsis, Secret};
+
+/// Error returned when a secret byte buffer is not valid UTF-8
+///
+/// Unlike `FromUtf8Error`, whose `Debug` output contains the input, this error only exposes the
+/// position of the invalid bytes. The input can be recovered as a secret through `recover`.
+pub struct Utf8SecretError<P = Ellipsis> {
+    bytes: Secret<Vec<u8>, P>,
+    err: Utf8Error,
+}
+
+impl<P> Utf8SecretError<P> {
+    /// Returns the length of the valid UTF-8 prefix, i.e. the offset of the first invalid byte
+    ///
+    /// See `Utf8Error::valid_up_to`.
+    #[inline]
+    pub fn valid_up_to(&self) -> usize {
+        self.err.valid_up_to()
+    }
+
+    /// Returns the underlying `Utf8Error`, which holds offsets only
+    #[inline]
+    pub fn utf8_error(&self) -> Utf8Error {
+        self.err
+    }
+
+    /// Returns the bytes that failed to convert, still wrapped in a secret
+    #[inline]
+    pub fn recover(self) -> Secret<Vec<u8>, P> {
+        self.bytes
+    }
+}
+
+impl<P> fmt::Display for Utf8SecretError<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "a confidential value is not valid UTF-8 (invalid byte at offset {})",
+            self.valid_up_to()
+        )
+    }
+}
+
+impl<P> fmt::Debug for Utf8SecretError<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Utf8SecretError")
+            .field("valid_up_to", &self.valid_up_to())
+            .finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<P> std::error::Error for Utf8SecretError<P> {}
+
+impl<P> Secret<Vec<u8>, P> {
+    /// Converts the held bytes into a secret string, without copying
+    ///
+    /// On failure, the bytes can be recovered from the returned error.
+    #[inline]
+    pub fn into_string_utf8(self) -> Result<Secret<String, P>, Utf8SecretError<P>> {
+        String::from_utf8(self.1)
+            .map(Secret::wrap)
+            .map_err(|err| Utf8SecretError {
+                err: err.utf8_error(),
+                bytes: Secret::wrap(err.into_bytes()),
+            })
+    }
+
+    /// Borrows the held bytes as a secret string slice
+    ///
+    /// `Utf8Error` only contains offsets, so neither its `Display` nor its `Debug` output reveals
+    /// any of the bytes.
+    #[inline]
+    pub fn as_str_utf8(&self) -> Result<Secret<&str, P>, Utf8Error> {
+        str::from_utf8(&self.1).map(Secret::wrap)
+    }
+}
@@ -0,0 +1,140 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::string::String;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use super::{ct, Secret, SharedSecret};
+
+struct Inner {
+    path: PathBuf,
+    current: SharedSecret<String>,
+}
+
+/// A secret read from a file, which is reloaded when the file changes
+///
+/// Kubernetes updates mounted secrets in place, by pointing a symlink to a new directory. A
+/// `WatchedSecret` picks up the new value on `reload`, or periodically once `watch` is called:
+///
+/// ```ignore
+/// let token = WatchedSecret::from_file("/var/run/secrets/api/token")?;
+/// let _watch = token.watch(Duration::from_secs(30));
+///
+/// client.authenticate(token.current().reveal());
+/// ```
+///
+/// The value is swapped atomically: `current` returns either the old or the new value, never a
+/// mix of both. Since a file that is rewritten in place is briefly empty, an empty file does not
+/// replace the current value. Clones share the value.
+#[derive(Clone)]
+pub struct WatchedSecret {
+    inner: Arc<Inner>,
+}
+
+impl WatchedSecret {
+    /// Reads the secret from the file at `path`, see `Secret::from_file`
+    pub fn from_file<F: AsRef<Path>>(path: F) -> io::Result<WatchedSecret> {
+        let path = path.as_ref().to_path_buf();
+        let secret = Secret::from_file(&path)?;
+
+        Ok(WatchedSecret {
+            inner: Arc::new(Inner {
+                path,
+                current: SharedSecret::from(secret),
+            }),
+        })
+    }
+
+    /// Returns the path of the file
+    #[inline]
+    pub fn path(&self) -> &Path {
+        &self.inner.path
+    }
+
+    /// Returns the current value
+    ///
+    /// The returned value stays the same while it is held, even if the secret is reloaded.
+    #[inline]
+    pub fn current(&self) -> Arc<Secret<String>> {
+        self.inner.current.get()
+    }
+
+    /// Reads the file again, returning whether the value changed
+    ///
+    /// On error, and if the file is empty, the current value is kept.
+    #[inline]
+    pub fn reload(&self) -> io::Result<bool> {
+        self.inner.reload()
+    }
+
+    /// Reloads the secret every `interval` on a background thread, until the handle is dropped
+    ///
+    /// Errors while reloading are ignored, keeping the current value, since the file may be missing
+    /// while it is replaced.
+    pub fn watch(&self, interval: Duration) -> WatchHandle {
+        let inner = Arc::clone(&self.inner);
+        let (stop, stopped) = mpsc::channel::<()>();
+
+        let thread = thread::Builder::new()
+            .name("sec-watch".into())
+            .spawn(move || {
+                while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+                    let _ = inner.reload();
+                }
+            })
+            .expect("could not spawn thread for watching secret");
+
+        WatchHandle {
+            stop: Some(stop),
+            thread: Some(thread),
+        }
+    }
+}
+
+impl Inner {
+    fn reload(&self) -> io::Result<bool> {
+        let secret: Secret<String> = Secret::from_file(&self.path)?;
+        if secret.1.is_empty() || ct::eq_bytes(secret.1.as_bytes(), self.current.get().1.as_bytes())
+        {
+            return Ok(false);
+        }
+
+        self.current.swap(secret);
+        Ok(true)
+    }
+}
+
+impl fmt::Debug for WatchedSecret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WatchedSecret")
+            .field("path", &self.inner.path)
+            .finish()
+    }
+}
+
+/// Handle of the background thread started by `WatchedSecret::watch`
+///
+/// Dropping the handle stops the thread and waits for it to finish.
+pub struct WatchHandle {
+    stop: Option<Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for WatchHandle {
+    fn drop(&mut self) {
+        // disconnects the channel, waking up the thread
+        drop(self.stop.take());
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl fmt::Debug for WatchHandle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("WatchHandle")
+    }
+}
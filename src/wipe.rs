@@ -0,0 +1,171 @@
+#[cfg(feature = "alloc")]
+use core::mem;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+#[cfg(feature = "alloc")]
+use super::audit;
+use super::Secret;
+
+/// Wipes the held value
+///
+/// Afterwards, the held value is in whatever state `T::zeroize` leaves it in, e.g. an empty
+/// `String`.
+impl<T: Zeroize> Zeroize for Secret<T> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.1.zeroize()
+    }
+}
+
+/// A `Secret` wipes its value on drop if the held value does
+impl<T: ZeroizeOnDrop> ZeroizeOnDrop for Secret<T> {}
+
+/// A `Secret<String>` that is guaranteed to be wiped when dropped
+///
+/// Conversions from `Secret<String>` are available through `From`. Converting back into a
+/// non-wiping value requires an explicit call to `reveal_into`.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SecretString(Secret<String>);
+
+#[cfg(feature = "alloc")]
+impl SecretString {
+    /// Creates a new wiping secret, taking ownership of `s` without copying it
+    #[inline]
+    pub fn from_string(s: String) -> SecretString {
+        SecretString(Secret::wrap(s))
+    }
+
+    /// Returns a reference to the held secret
+    #[inline]
+    pub fn as_secret(&self) -> &Secret<String> {
+        &self.0
+    }
+
+    /// Creates a new secret string slice from the held value without revealing it
+    #[inline]
+    pub fn as_str(&self) -> Secret<&str> {
+        self.0.as_str()
+    }
+
+    /// **Reveals** the held value as a string slice
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `as_secret` and `reveal_with`")
+    )]
+    pub fn reveal_str(&self) -> &str {
+        audit::revealed::<String>();
+        &self.0 .1
+    }
+
+    /// Returns and **reveal** the held value
+    ///
+    /// The returned `String` is no longer wiped on drop.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "reveal only in approved modules, see `RevealToken`")
+    )]
+    pub fn reveal_into(mut self) -> String {
+        audit::revealed::<String>();
+        mem::take(&mut (self.0).1)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Secret<String>> for SecretString {
+    #[inline]
+    fn from(secret: Secret<String>) -> SecretString {
+        SecretString(secret)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Drop for SecretString {
+    #[inline]
+    fn drop(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+/// A `Secret<Vec<u8>>` that is guaranteed to be wiped when dropped
+///
+/// See `SecretString`.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SecretBytes(Secret<Vec<u8>>);
+
+#[cfg(feature = "alloc")]
+impl SecretBytes {
+    /// Creates a new wiping secret, taking ownership of `bytes` without copying them
+    #[inline]
+    pub fn from_vec(bytes: Vec<u8>) -> SecretBytes {
+        SecretBytes(Secret::wrap(bytes))
+    }
+
+    /// Returns a reference to the held secret
+    #[inline]
+    pub fn as_secret(&self) -> &Secret<Vec<u8>> {
+        &self.0
+    }
+
+    /// Creates a new secret byte slice from the held value without revealing it
+    #[inline]
+    pub fn as_slice(&self) -> Secret<&[u8]> {
+        self.0.as_slice()
+    }
+
+    /// **Reveals** the held value as a byte slice
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "use `as_secret` and `reveal_with`")
+    )]
+    pub fn reveal_bytes(&self) -> &[u8] {
+        audit::revealed::<Vec<u8>>();
+        &self.0 .1
+    }
+
+    /// Returns and **reveal** the held value
+    ///
+    /// The returned `Vec` is no longer wiped on drop.
+    #[inline]
+    #[cfg_attr(feature = "audit", track_caller)]
+    #[cfg_attr(
+        feature = "capability",
+        deprecated(note = "reveal only in approved modules, see `RevealToken`")
+    )]
+    pub fn reveal_into(mut self) -> Vec<u8> {
+        audit::revealed::<Vec<u8>>();
+        mem::take(&mut (self.0).1)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Secret<Vec<u8>>> for SecretBytes {
+    #[inline]
+    fn from(secret: Secret<Vec<u8>>) -> SecretBytes {
+        SecretBytes(secret)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Drop for SecretBytes {
+    #[inline]
+    fn drop(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ZeroizeOnDrop for SecretString {}
+
+#[cfg(feature = "alloc")]
+impl ZeroizeOnDrop for SecretBytes {}
@@ -3,6 +3,19 @@
 set -e
 
 cargo fmt -- --check
-cargo clippy --all-features
+# ord and hashed-ord are mutually exclusive, so --all-features cannot be used
+cargo clippy --features "ord cmp-plain ops anyhow audit typed-debug danger-reveal-debug danger-deref rand obfuscate prompt mlock encrypt keyring async watch zxcvbn hkdf base64 hex subtle sha2 zeroize secrecy url serde diesel rocket"
+cargo clippy --features hashed-ord
+cargo clippy --features stats -- -D warnings
+cargo clippy --features capability -- -D warnings
+# only has an effect on Windows
+cargo clippy --features windows-protect -- -D warnings
 cargo clippy
-cargo test --all-features
+# danger-reveal-debug disables redaction and danger-deref reopens it, so their tests are run
+# separately
+cargo test --features "ord cmp-plain ops anyhow audit typed-debug rand obfuscate prompt mlock encrypt keyring async watch zxcvbn hkdf base64 hex subtle sha2 zeroize secrecy url serde diesel rocket"
+cargo test --features danger-reveal-debug danger_reveal_debug
+cargo test --features danger-deref danger_deref
+cargo test --features hashed-ord hashed_ord
+cargo test --features stats stats
+cargo test --features capability capability